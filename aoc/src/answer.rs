@@ -0,0 +1,237 @@
+use std::{
+    cmp::Ordering,
+    convert::Infallible,
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// The answer to one part of a puzzle.
+///
+/// Integer variants compare and hash by value, so `Answer::Unsigned(5)` equals
+/// `Answer::Signed(5)`. Numbers sort before strings, which sort before grids.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Big(#[serde(with = "big")] i128),
+    String(String),
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// The capital letters drawn by a grid answer, `None` if it isn't a grid
+    /// or [`ocr`](crate::ocr) can't read it.
+    pub fn letters(&self) -> Option<String> {
+        match self {
+            Self::Grid(rows) => crate::ocr(rows).ok(),
+            _ => None,
+        }
+    }
+
+    fn as_integer(&self) -> Option<i128> {
+        match *self {
+            Self::Unsigned(n) => Some(n as i128),
+            Self::Signed(n) => Some(n as i128),
+            Self::Big(n) => Some(n),
+            Self::String(_) | Self::Grid(_) => None,
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Unsigned(_) | Self::Signed(_) | Self::Big(_) => 0,
+            Self::String(_) => 1,
+            Self::Grid(_) => 2,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Answer {}
+
+impl Ord for Answer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::String(a), Self::String(b)) => a.cmp(b),
+            (Self::Grid(a), Self::Grid(b)) => a.cmp(b),
+            _ => match (self.as_integer(), other.as_integer()) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                _ => self.rank().cmp(&other.rank()),
+            },
+        }
+    }
+}
+
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rank().hash(state);
+        match self {
+            Self::String(s) => s.hash(state),
+            Self::Grid(rows) => rows.hash(state),
+            _ => self.as_integer().hash(state),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsigned(n) => n.fmt(f),
+            Self::Signed(n) => n.fmt(f),
+            Self::Big(n) => n.fmt(f),
+            Self::String(s) => s.fmt(f),
+            Self::Grid(rows) => f.write_str(&rows.join("\n")),
+        }
+    }
+}
+
+/// Parses the text produced by [`Display`]. Multi-line text becomes a grid,
+/// integers get the narrowest integer variant, and anything else is a string.
+///
+/// Text doesn't record the variant, so `Answer::from("42")` comes back as a
+/// number and a grid of one row as a string. Only the printed answer survives
+/// the round trip, which is what expected answers are compared by.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains('\n') {
+            return Ok(Self::Grid(s.split('\n').map(str::to_owned).collect()));
+        }
+        Ok(if let Ok(n) = s.parse() {
+            Self::Unsigned(n)
+        } else if let Ok(n) = s.parse() {
+            Self::Signed(n)
+        } else if let Ok(n) = s.parse() {
+            Self::Big(n)
+        } else {
+            Self::String(s.to_owned())
+        })
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($variant:ident as $inner:ty: $($t:ty)*) => ($(
+        impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Self::$variant(value as $inner)
+            }
+        }
+    )*)
+}
+
+impl_from_integer! { Unsigned as u64: u8 u16 u32 u64 usize }
+impl_from_integer! { Signed as i64: i8 i16 i32 i64 isize }
+impl_from_integer! { Big as i128: i128 }
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(rows: Vec<String>) -> Self {
+        Self::Grid(rows)
+    }
+}
+
+/// Big integers are written as strings so JSON readers without 128-bit
+/// numbers don't round them.
+mod big {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(n: &i128, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(n)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i128, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_compare_by_value() {
+        assert_eq!(Answer::from(5usize), Answer::from(5i64));
+        assert_eq!(Answer::Big(7), Answer::Unsigned(7));
+        assert!(Answer::from(-1i32) < Answer::from(0u8));
+        assert!(Answer::from(u64::MAX) < Answer::Big(u64::MAX as i128 + 1));
+        assert!(Answer::from(1u64) < Answer::from("1"));
+        assert!(Answer::from("ZZZ") < Answer::from(vec!["#".to_string()]));
+    }
+
+    #[test]
+    fn text_round_trip() {
+        for answer in [
+            Answer::Unsigned(42),
+            Answer::Signed(-42),
+            Answer::Big(i128::MIN),
+            Answer::from("CMZ"),
+            Answer::from(vec!["#..#".to_string(), ".##.".to_string()]),
+        ] {
+            let parsed: Answer = answer.to_string().parse().unwrap();
+            assert_eq!(parsed, answer);
+        }
+
+        for answer in [
+            Answer::Unsigned(0),
+            Answer::Signed(i64::MIN),
+            Answer::Big(i128::MAX),
+            Answer::from("42"),
+            Answer::from("-7"),
+            Answer::from(""),
+            Answer::from(vec!["#..#".to_string()]),
+            Answer::from(vec!["#".to_string(), String::new(), String::new()]),
+            Answer::from(vec![String::new(), ".#".to_string()]),
+        ] {
+            let parsed: Answer = answer.to_string().parse().unwrap();
+            assert_eq!(parsed.to_string(), answer.to_string(), "{answer:?}");
+        }
+    }
+
+    #[test]
+    fn json_round_trip() {
+        for answer in [
+            Answer::Unsigned(u64::MAX),
+            Answer::Signed(i64::MIN),
+            Answer::Big(i128::MAX),
+            Answer::from("2=-1=0"),
+            Answer::from(vec!["##".to_string(), "..".to_string()]),
+        ] {
+            let json = serde_json::to_string(&answer).unwrap();
+            let parsed: Answer = serde_json::from_str(&json).unwrap();
+            assert_eq!(format!("{parsed:?}"), format!("{answer:?}"));
+        }
+        assert_eq!(
+            serde_json::to_string(&Answer::Big(1)).unwrap(),
+            r#"{"big":"1"}"#
+        );
+    }
+}
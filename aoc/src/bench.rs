@@ -0,0 +1,164 @@
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Params, Part, Solution};
+
+/// Summary of repeated timings of the same step.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    #[serde(with = "nanos")]
+    pub min: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub mean: Duration,
+    #[serde(with = "nanos")]
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Some(Self {
+            samples: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Timings of parsing and of each implemented part of one solution.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse: Option<Stats>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub one: Option<Stats>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub two: Option<Stats>,
+}
+
+/// Runs a solution repeatedly, either a fixed number of times or until its
+/// time budget is used up.
+#[derive(Debug, Clone)]
+pub struct Bench {
+    pub warmup: usize,
+    pub iterations: Option<usize>,
+    pub budget: Duration,
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: None,
+            budget: Duration::from_secs(3),
+        }
+    }
+}
+
+impl Bench {
+    /// Benchmarks the implemented parts of `solution`, failing if one of them
+    /// fails or none is implemented.
+    pub fn run(
+        &self,
+        solution: &dyn Solution,
+        input: &str,
+        params: &Params,
+    ) -> Result<Measurement, Error> {
+        let mut parts = vec![];
+        for run in solution.run(input, &[Part::One, Part::Two], params).parts {
+            match run.result {
+                Ok(_) => parts.push(run.part),
+                Err(Error::NotImplemented) => (),
+                Err(err) => return Err(err),
+            }
+        }
+        if parts.is_empty() {
+            return Err(Error::NotImplemented);
+        }
+
+        // The run above, which also finds the implemented parts, warms up too.
+        for _ in 1..self.warmup {
+            solution.run(input, &parts, params);
+        }
+
+        let mut parse = vec![];
+        let mut one = vec![];
+        let mut two = vec![];
+
+        let start = Instant::now();
+        let mut iterations = 0;
+        while match self.iterations {
+            Some(n) => iterations < n,
+            None => iterations == 0 || start.elapsed() < self.budget,
+        } {
+            let run = solution.run(input, &parts, params);
+            parse.extend(run.parse);
+            for part in run.parts {
+                match part.part {
+                    Part::One => one.push(part.time),
+                    Part::Two => two.push(part.time),
+                }
+            }
+            iterations += 1;
+        }
+
+        Ok(Measurement {
+            parse: Stats::new(&parse),
+            one: Stats::new(&one),
+            two: Stats::new(&two),
+        })
+    }
+}
+
+mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::new(&samples).unwrap();
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+        assert_eq!(Stats::new(&[]), None);
+    }
+}
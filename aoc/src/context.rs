@@ -0,0 +1,254 @@
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, SyncSender},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{Error, Frame, Params, Part, PartRun, Run, Solution, Visualize};
+
+thread_local! {
+    static CURRENT: RefCell<Context> = RefCell::new(Context::default());
+}
+
+/// Deadline of the solution running on this thread. Long loops call
+/// [`Context::check`] to give up with [`Error::Timeout`] once it has passed.
+///
+/// Rayon closures run on other threads, so take the context with
+/// [`Context::current`] before going parallel and check that one.
+///
+/// The context also carries where frames of a [`Visualize`]d run go.
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    deadline: Option<Instant>,
+    cancelled: Arc<AtomicBool>,
+    /// Shared with the clones of the context, so that cancelling drops the
+    /// sender of a solution still running in the background.
+    frames: Arc<Mutex<Option<SyncSender<Frame>>>>,
+}
+
+impl Context {
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            deadline: Instant::now().checked_add(timeout),
+            cancelled: Default::default(),
+            frames: Default::default(),
+        }
+    }
+
+    /// Sends frames emitted under this context to `frames`. Sending blocks
+    /// while the receiver is behind, so it sets the pace of the solution.
+    pub fn with_frames(mut self, frames: SyncSender<Frame>) -> Self {
+        self.frames = Arc::new(Mutex::new(Some(frames)));
+        self
+    }
+
+    fn frames(&self) -> Option<SyncSender<Frame>> {
+        self.frames.lock().unwrap().clone()
+    }
+
+    /// The context of the solution running on this thread, one without a
+    /// deadline if there is none.
+    pub fn current() -> Self {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Makes this the current context while running `f`.
+    pub fn enter<T>(&self, f: impl FnOnce() -> T) -> T {
+        let previous = CURRENT.with(|current| current.replace(self.clone()));
+        let result = f();
+        CURRENT.with(|current| current.replace(previous));
+        result
+    }
+
+    /// Gives up on the solution, which also stops sending its frames so that
+    /// a viewer doesn't wait for it to end.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.frames.lock().unwrap().take();
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Sends a frame of `world` if frames are being watched, and the solution
+    /// hasn't run out of time.
+    pub fn emit(&self, world: &impl Visualize) {
+        if let Some(frames) = self.frames() {
+            if !self.is_expired() {
+                frames.send(world.frame()).ok();
+            }
+        }
+    }
+
+    pub fn check(&self) -> Result<(), Error> {
+        if self.is_expired() {
+            Err(Error::Timeout)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Sends a frame of `world` through the current [`Context`], see
+/// [`Context::emit`].
+pub fn emit_frame(world: &impl Visualize) {
+    CURRENT.with(|current| current.borrow().emit(world))
+}
+
+/// Runs `solution` on its own thread and gives up after `timeout`, reporting
+/// every part as [`Error::Timeout`].
+///
+/// Solutions that check their [`Context`] stop soon after. Others keep running
+/// in the background until the process exits.
+pub fn run_with_timeout(
+    solution: &'static dyn Solution,
+    input: String,
+    parts: &[Part],
+    params: Params,
+    timeout: Duration,
+) -> Run {
+    let mut context = Context::with_timeout(timeout);
+    if let Some(frames) = Context::current().frames() {
+        context = context.with_frames(frames);
+    }
+    let (sender, receiver) = mpsc::channel();
+
+    let worker = context.clone();
+    let worker_parts = parts.to_vec();
+    std::thread::spawn(move || {
+        let run = worker.enter(|| solution.run(&input, &worker_parts, &params));
+        sender.send(run).ok();
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(run) => run,
+        Err(_) => {
+            context.cancel();
+            Run {
+                parse: None,
+                parts: parts
+                    .iter()
+                    .map(|&part| PartRun {
+                        part,
+                        result: Err(Error::Timeout),
+                        time: timeout,
+                        memory: None,
+                    })
+                    .collect(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AocResult, Date};
+
+    #[derive(Debug)]
+    struct Forever;
+
+    impl Solution for Forever {
+        fn part_one(&self, _input: &str) -> AocResult {
+            let context = Context::current();
+            loop {
+                context.check()?;
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        }
+    }
+
+    impl Date for Forever {
+        fn year(&self) -> usize {
+            2015
+        }
+
+        fn day(&self) -> usize {
+            1
+        }
+    }
+
+    #[test]
+    fn times_out() {
+        let run = run_with_timeout(
+            &Forever,
+            String::new(),
+            &[Part::One],
+            Params::new(),
+            Duration::from_millis(20),
+        );
+        assert_eq!(run.parts[0].result, Err(Error::Timeout));
+
+        let context = Context::with_timeout(Duration::ZERO);
+        assert_eq!(context.enter(|| Forever.part_one("")), Err(Error::Timeout));
+        assert_eq!(Context::current().check(), Ok(()));
+    }
+
+    struct Counter(usize);
+
+    impl Visualize for Counter {
+        fn frame(&self) -> Frame {
+            Frame::new(vec![self.0.to_string()])
+        }
+    }
+
+    #[test]
+    fn emits_frames() {
+        emit_frame(&Counter(0));
+
+        let (sender, receiver) = mpsc::sync_channel(4);
+        Context::default()
+            .with_frames(sender)
+            .enter(|| (1..=3).for_each(|i| emit_frame(&Counter(i))));
+        let frames: Vec<_> = receiver.iter().map(|frame| frame.to_string()).collect();
+        assert_eq!(frames, ["1", "2", "3"]);
+    }
+
+    /// Emits frames without ever checking its context.
+    #[derive(Debug)]
+    struct Emitting;
+
+    impl Solution for Emitting {
+        fn part_one(&self, _input: &str) -> AocResult {
+            loop {
+                emit_frame(&Counter(0));
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        }
+    }
+
+    impl Date for Emitting {
+        fn year(&self) -> usize {
+            2015
+        }
+
+        fn day(&self) -> usize {
+            2
+        }
+    }
+
+    #[test]
+    fn drops_frames_on_timeout() {
+        let (sender, receiver) = mpsc::sync_channel(4);
+        let run = Context::default().with_frames(sender).enter(|| {
+            run_with_timeout(
+                &Emitting,
+                String::new(),
+                &[Part::One],
+                Params::new(),
+                Duration::from_millis(20),
+            )
+        });
+        assert_eq!(run.parts[0].result, Err(Error::Timeout));
+        // Ends although the solution is still running.
+        assert!(receiver.iter().count() > 0);
+    }
+}
@@ -0,0 +1,197 @@
+use std::{fmt, num::ParseIntError};
+
+/// Longest snippet of input quoted in a parse error.
+const SNIPPET_LEN: usize = 40;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Error {
+    NotImplemented,
+    /// The input didn't match what the parser expected. `line` and `column`
+    /// are 1-based; `snippet` is the input starting at the failure.
+    Parse {
+        line: usize,
+        column: usize,
+        expected: String,
+        snippet: String,
+    },
+    /// The input parsed but can't be solved, or it failed to parse somewhere
+    /// we don't know the position of.
+    InvalidInput(String),
+    /// The solution ran past the deadline of its [`Context`](crate::Context).
+    Timeout,
+    /// The solution panicked while running. `location` is where, if the
+    /// panic happened on the thread running the solution.
+    Panicked {
+        message: String,
+        location: Option<String>,
+    },
+    Other(String),
+}
+
+impl Error {
+    /// Builds a [`Error::Parse`] for a failure at `at`, which must be a
+    /// subslice of `input`, such as a line or the rest left over by nom.
+    pub fn parse(input: &str, at: &str, expected: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(input.len().saturating_sub(at.len()));
+        // Guessed offsets of slices from elsewhere can fall inside a character.
+        let offset = input.floor_char_boundary(offset);
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Self::Parse {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            snippet: input[offset..]
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .take(SNIPPET_LEN)
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotImplemented => f.write_str("not implemented"),
+            Self::Parse {
+                line,
+                column,
+                expected,
+                snippet,
+            } => write!(
+                f,
+                "parse error at line {line}, column {column}: expected {expected}, found {snippet:?}"
+            ),
+            Self::InvalidInput(message) => write!(f, "invalid input: {message}"),
+            Self::Timeout => f.write_str("timed out"),
+            Self::Panicked {
+                message,
+                location: Some(location),
+            } => write!(f, "panicked at {location}: {message}"),
+            Self::Panicked {
+                message,
+                location: None,
+            } => write!(f, "panicked: {message}"),
+            Self::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseIntError> for Error {
+    fn from(err: ParseIntError) -> Self {
+        Self::InvalidInput(err.to_string())
+    }
+}
+
+impl From<parse_display::ParseError> for Error {
+    fn from(err: parse_display::ParseError) -> Self {
+        Self::InvalidInput(err.to_string())
+    }
+}
+
+/// Without the whole input nom errors can't be placed on a line, use
+/// [`Locate::locate`] where it is available.
+impl From<nom::Err<nom::error::Error<&str>>> for Error {
+    fn from(err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Incomplete(_) => Self::InvalidInput("unexpected end of input".into()),
+            nom::Err::Error(err) | nom::Err::Failure(err) => {
+                let found: String = err.input.chars().take(SNIPPET_LEN).collect();
+                Self::InvalidInput(format!("expected {}, found {found:?}", expected(err.code)))
+            }
+        }
+    }
+}
+
+fn expected(kind: nom::error::ErrorKind) -> String {
+    kind.description().to_lowercase()
+}
+
+/// Turns nom results into [`Error::Parse`] with the position of the failure
+/// within the whole puzzle input.
+pub trait Locate<O> {
+    fn locate(self, input: &str) -> Result<O, Error>;
+}
+
+impl<'a, O> Locate<O> for Result<(&'a str, O), nom::Err<nom::error::Error<&'a str>>> {
+    fn locate(self, input: &str) -> Result<O, Error> {
+        match self {
+            Ok((_, output)) => Ok(output),
+            Err(nom::Err::Incomplete(_)) => Err(Error::parse(input, "", "more input")),
+            Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+                Err(Error::parse(input, err.input, expected(err.code)))
+            }
+        }
+    }
+}
+
+impl<'a, O> Locate<O> for Result<(&'a str, O), nom::error::Error<&'a str>> {
+    fn locate(self, input: &str) -> Result<O, Error> {
+        self.map_err(nom::Err::Error).locate(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{bytes::complete::tag, character::complete::u32, sequence::preceded, IResult};
+
+    use super::*;
+
+    fn parse_line(i: &str) -> IResult<&str, u32> {
+        preceded(tag("x="), u32)(i)
+    }
+
+    #[test]
+    fn parse_error_position() {
+        let input = "x=1\nx=2\nx=oops\n";
+        let line = input.lines().nth(2).unwrap();
+        assert_eq!(
+            parse_line(line).locate(input),
+            Err(Error::Parse {
+                line: 3,
+                column: 3,
+                expected: "digit".into(),
+                snippet: "oops".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_error_at_end() {
+        assert_eq!(
+            Error::parse("ab\n", "", "more"),
+            Error::Parse {
+                line: 2,
+                column: 1,
+                expected: "more".into(),
+                snippet: "".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_error_elsewhere() {
+        // Not a slice of the input, guessed to end it, inside the arrow.
+        let at = String::from("ab");
+        assert_eq!(
+            Error::parse("→x", &at, "x"),
+            Error::Parse {
+                line: 1,
+                column: 1,
+                expected: "x".into(),
+                snippet: "→x".into(),
+            }
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use std::fmt::Write;
+
+use crate::{Params, Part, Solution};
+
+/// Solves `part` of an example and compares the answer as printed with
+/// `expected`, describing the difference line by line when they don't match.
+pub fn check_example(
+    solution: &dyn Solution,
+    input: &str,
+    part: Part,
+    params: &Params,
+    expected: &str,
+) -> Result<(), String> {
+    match solution.solve_with(input, part, params) {
+        Ok(answer) if answer.to_string() == expected => Ok(()),
+        Ok(answer) => Err(diff(expected, &answer.to_string())),
+        Err(err) => Err(format!("expected {expected:?}, got error: {err}")),
+    }
+}
+
+/// Single line answers side by side, multi-line ones with `-` before expected
+/// and `+` before actual lines where they differ.
+pub fn diff(expected: &str, actual: &str) -> String {
+    if !expected.contains('\n') && !actual.contains('\n') {
+        return format!("expected {expected:?}, got {actual:?}");
+    }
+
+    let expected: Vec<_> = expected.lines().collect();
+    let actual: Vec<_> = actual.lines().collect();
+    let mut out = String::from("answer differs from the example (-expected +actual):\n");
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => writeln!(out, "  {e}"),
+            (e, a) => {
+                if let Some(e) = e {
+                    writeln!(out, "- {e}").unwrap();
+                }
+                match a {
+                    Some(a) => writeln!(out, "+ {a}"),
+                    None => Ok(()),
+                }
+            }
+        }
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diffs_lines() {
+        assert_eq!(diff("24000", "24001"), r#"expected "24000", got "24001""#);
+        assert_eq!(
+            diff("##..\n.##.\n..##", "##..\n.#..\n..##\n####"),
+            "answer differs from the example (-expected +actual):\n  ##..\n- .##.\n+ .#..\n  ..##\n+ ####\n"
+        );
+    }
+}
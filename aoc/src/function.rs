@@ -0,0 +1,138 @@
+use std::{collections::BTreeMap, fmt, sync::OnceLock};
+
+use crate::{AocResult, Date, Error, Part, Problem, Solution};
+
+/// One part of a solution written as a plain function, submitted to
+/// `inventory` by `#[aoc(year = .., day = .., part = ..)]`.
+pub struct PartFn {
+    pub year: usize,
+    pub day: usize,
+    pub part: Part,
+    /// Path of the function, for messages about it.
+    pub name: &'static str,
+    pub solve: fn(&str) -> AocResult,
+}
+
+inventory::collect!(PartFn);
+
+/// The functions registered for the parts of one day.
+pub(crate) struct FnSolution {
+    year: usize,
+    day: usize,
+    one: Option<&'static PartFn>,
+    two: Option<&'static PartFn>,
+}
+
+impl fmt::Debug for FnSolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<_> = [self.one, self.two]
+            .into_iter()
+            .flatten()
+            .map(|part| part.name)
+            .collect();
+        f.write_str(&names.join(" + "))
+    }
+}
+
+impl Date for FnSolution {
+    fn year(&self) -> usize {
+        self.year
+    }
+
+    fn day(&self) -> usize {
+        self.day
+    }
+}
+
+impl Solution for FnSolution {
+    fn part_one(&self, input: &str) -> AocResult {
+        self.one
+            .map_or(Err(Error::NotImplemented), |part| (part.solve)(input))
+    }
+
+    fn part_two(&self, input: &str) -> AocResult {
+        self.two
+            .map_or(Err(Error::NotImplemented), |part| (part.solve)(input))
+    }
+}
+
+/// Merges the parts of each day into one solution, reporting parts that were
+/// registered twice.
+pub(crate) fn solutions(
+    parts: impl IntoIterator<Item = &'static PartFn>,
+) -> (Vec<FnSolution>, Vec<Problem>) {
+    let mut days: BTreeMap<(usize, usize), FnSolution> = BTreeMap::new();
+    let mut problems = vec![];
+
+    for part in parts {
+        let solution = days.entry((part.year, part.day)).or_insert(FnSolution {
+            year: part.year,
+            day: part.day,
+            one: None,
+            two: None,
+        });
+        let slot = match part.part {
+            Part::One => &mut solution.one,
+            Part::Two => &mut solution.two,
+        };
+        match slot {
+            Some(first) => problems.push(Problem::Duplicate {
+                year: part.year,
+                day: part.day,
+                first: first.name.to_string(),
+                second: part.name.to_string(),
+            }),
+            None => *slot = Some(part),
+        }
+    }
+
+    (days.into_values().collect(), problems)
+}
+
+/// The solutions of every function submitted to `inventory`, merged once for
+/// all registries of the process.
+pub(crate) fn registered() -> &'static (Vec<FnSolution>, Vec<Problem>) {
+    static REGISTERED: OnceLock<(Vec<FnSolution>, Vec<Problem>)> = OnceLock::new();
+    REGISTERED.get_or_init(|| solutions(inventory::iter::<PartFn>))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static ONE: PartFn = PartFn {
+        year: 2021,
+        day: 1,
+        part: Part::One,
+        name: "one",
+        solve: |_| Ok(1usize.into()),
+    };
+    static TWO: PartFn = PartFn {
+        year: 2021,
+        day: 1,
+        part: Part::Two,
+        name: "two",
+        solve: |_| Ok(2usize.into()),
+    };
+    static AGAIN: PartFn = PartFn {
+        name: "again",
+        ..ONE
+    };
+
+    #[test]
+    fn merges_parts() {
+        let (solutions, problems) = solutions([&ONE, &TWO, &AGAIN]);
+        assert_eq!(solutions.len(), 1);
+        assert_eq!(format!("{:?}", solutions[0]), "one + two");
+        assert_eq!(solutions[0].part_two(""), Ok(2usize.into()));
+        assert_eq!(
+            problems,
+            [Problem::Duplicate {
+                year: 2021,
+                day: 1,
+                first: "one".into(),
+                second: "again".into(),
+            }]
+        );
+    }
+}
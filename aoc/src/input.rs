@@ -0,0 +1,201 @@
+use std::{borrow::Cow, str::FromStr};
+
+use crate::Error;
+
+/// Puzzle input with CRLF line endings and trailing newlines removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input<'a>(Cow<'a, str>);
+
+/// A blank line separated block starting with `name:`, like the
+/// `seed-to-soil map:` blocks of 2023 day 5.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub name: &'a str,
+    /// Everything after the colon, without leading whitespace.
+    pub body: &'a str,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(raw: &'a str) -> Self {
+        let normalized = if raw.contains('\r') {
+            Cow::Owned(raw.replace("\r\n", "\n"))
+        } else {
+            Cow::Borrowed(raw)
+        };
+        Self(match normalized {
+            Cow::Borrowed(s) => Cow::Borrowed(s.trim_end_matches('\n')),
+            Cow::Owned(s) => Cow::Owned(s.trim_end_matches('\n').to_string()),
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.0.lines()
+    }
+
+    /// Groups of lines separated by one or more blank lines.
+    pub fn blocks(&self) -> impl Iterator<Item = &str> {
+        self.0
+            .split("\n\n")
+            .map(|block| block.trim_matches('\n'))
+            .filter(|block| !block.is_empty())
+    }
+
+    /// All blocks that start with a `name:` header.
+    pub fn sections(&self) -> Result<Vec<Section<'_>>, Error> {
+        self.blocks()
+            .map(|block| match block.split_once(':') {
+                Some((name, body)) if !name.contains('\n') => Ok(Section {
+                    name: name.trim(),
+                    body: body.trim_start(),
+                }),
+                _ => Err(Error::parse(&self.0, block, "section header")),
+            })
+            .collect()
+    }
+
+    /// Body of the section called `name`.
+    pub fn section(&self, name: &str) -> Result<&str, Error> {
+        self.sections()?
+            .into_iter()
+            .find(|section| section.name == name)
+            .map(|section| section.body)
+            .ok_or_else(|| Error::InvalidInput(format!("no section {name:?}")))
+    }
+
+    /// Every integer in the input, see [`ints`].
+    pub fn ints<T: FromStr>(&self) -> Result<Vec<T>, Error> {
+        ints(&self.0)
+    }
+
+    pub fn grid(&self) -> Result<Vec<Vec<char>>, Error> {
+        self.grid_with(Some)
+    }
+
+    /// Parses a rectangular grid of characters, each converted with `cell`.
+    /// A character for which `cell` returns `None` is a parse error.
+    pub fn grid_with<T>(
+        &self,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Vec<Vec<T>>, Error> {
+        let mut width = None;
+        let mut grid = vec![];
+
+        for line in self.lines() {
+            let row = line
+                .char_indices()
+                .map(|(i, c)| cell(c).ok_or_else(|| Error::parse(&self.0, &line[i..], "grid cell")))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            match width {
+                None => width = Some(row.len()),
+                Some(width) if width != row.len() => {
+                    return Err(Error::parse(&self.0, line, format!("{width} cells")))
+                }
+                Some(_) => (),
+            }
+            grid.push(row);
+        }
+
+        Ok(grid)
+    }
+}
+
+impl<'a> From<&'a str> for Input<'a> {
+    fn from(raw: &'a str) -> Self {
+        Self::new(raw)
+    }
+}
+
+/// Every integer in `s`, with a leading `-` making it negative. Anything
+/// between the numbers is skipped.
+pub fn ints<T: FromStr>(s: &str) -> Result<Vec<T>, Error> {
+    ints_in(s, s)
+}
+
+/// Like [`ints`] for a part of `input`, such as a line, with errors located
+/// in the whole input.
+pub fn ints_in<T: FromStr>(input: &str, s: &str) -> Result<Vec<T>, Error> {
+    let mut numbers = vec![];
+    let mut rest = s;
+
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+        let negative = rest[..start].ends_with('-');
+        let end = rest[start..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(rest.len(), |len| start + len);
+        let number = &rest[if negative { start - 1 } else { start }..end];
+
+        numbers.push(
+            number
+                .parse()
+                .map_err(|_| Error::parse(input, number, "integer in range"))?,
+        );
+        rest = &rest[end..];
+    }
+
+    Ok(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes() {
+        let input = Input::new("a\r\nb\r\n\r\nc\r\n\n");
+        assert_eq!(input.as_str(), "a\nb\n\nc");
+        assert_eq!(input.blocks().collect::<Vec<_>>(), ["a\nb", "c"]);
+    }
+
+    #[test]
+    fn sections() {
+        let input = Input::new("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n");
+        assert_eq!(input.section("seeds"), Ok("79 14"));
+        assert_eq!(input.section("seed-to-soil map"), Ok("50 98 2\n52 50 48"));
+        assert!(input.section("soil-to-fertilizer map").is_err());
+    }
+
+    #[test]
+    fn ints() {
+        assert_eq!(
+            super::ints::<i64>("Sensor at x=2, y=-18: x-1 y=0"),
+            Ok(vec![2, -18, -1, 0])
+        );
+        assert!(super::ints::<u8>("300").is_err());
+
+        let input = "1 2\n3 300";
+        let line = input.lines().nth(1).unwrap();
+        assert_eq!(
+            ints_in::<u8>(input, line),
+            Err(Error::Parse {
+                line: 2,
+                column: 3,
+                expected: "integer in range".into(),
+                snippet: "300".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn grid() {
+        let input = Input::new("12\n34\n");
+        assert_eq!(
+            input.grid_with(|c| c.to_digit(10)),
+            Ok(vec![vec![1, 2], vec![3, 4]])
+        );
+        assert_eq!(
+            Input::new("12\n3x").grid_with(|c| c.to_digit(10)),
+            Err(Error::Parse {
+                line: 2,
+                column: 2,
+                expected: "grid cell".into(),
+                snippet: "x".into(),
+            })
+        );
+        assert!(Input::new("12\n3").grid().is_err());
+    }
+}
@@ -1,6 +1,40 @@
 use std::str::FromStr;
 
-pub use aoc_derive::Date;
+pub use aoc_derive::{aoc, Date, FromParams};
+/// Solutions add spans and events for `-v` and `--profile` through this.
+pub use tracing;
+
+#[doc(hidden)]
+pub use inventory;
+
+mod answer;
+mod bench;
+mod context;
+mod error;
+mod example;
+mod function;
+mod input;
+mod memory;
+mod ocr;
+mod panic;
+mod params;
+mod registry;
+mod run;
+mod visualize;
+
+pub use answer::Answer;
+pub use bench::{Bench, Measurement, Stats};
+pub use context::{emit_frame, run_with_timeout, Context};
+pub use error::{Error, Locate};
+pub use example::{check_example, diff};
+pub use function::PartFn;
+pub use input::{ints, ints_in, Input, Section};
+pub use memory::{CountingAllocator, Memory};
+pub use ocr::{ocr, ocr_with, Glyphs};
+pub use params::{FromParams, Params};
+pub use registry::{Problem, Registry};
+pub use run::{PartRun, Run};
+pub use visualize::{Frame, Visualize};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Part {
@@ -23,17 +57,17 @@ impl FromStr for Part {
 pub type Day = i32;
 pub type Year = i32;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
-pub enum Error {
-    NotImplemented,
-    Other(String),
-}
-
-pub type AocResult = Result<Box<dyn std::fmt::Display>, Error>;
+pub type AocResult = Result<Answer, Error>;
 
 pub trait Date {
     fn year(&self) -> usize;
     fn day(&self) -> usize;
+
+    /// Name of an alternative implementation of the day, `None` for the main
+    /// one.
+    fn variant(&self) -> Option<&'static str> {
+        None
+    }
 }
 
 pub trait Solution: Sync + std::fmt::Debug + Date {
@@ -53,6 +87,110 @@ pub trait Solution: Sync + std::fmt::Debug + Date {
     fn part_two(&self, input: &str) -> AocResult {
         Err(Error::NotImplemented)
     }
+
+    /// Solves `part` with puzzle parameters overriding the defaults for real
+    /// inputs. Solutions without parameters reject any that are given.
+    fn solve_with(&self, input: &str, part: Part, params: &Params) -> AocResult {
+        <()>::from_params(params)?;
+        self.solve(input, part)
+    }
+
+    /// Solves each of `parts`, timing parsing and solving separately where the
+    /// solution allows it.
+    fn run(&self, input: &str, parts: &[Part], params: &Params) -> Run {
+        let _run = tracing::info_span!("run", year = self.year(), day = self.day()).entered();
+        Run {
+            parse: None,
+            parts: parts
+                .iter()
+                .map(|&part| {
+                    let _part = tracing::info_span!("solve", ?part).entered();
+                    PartRun::timed(part, || self.solve_with(input, part, params))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A solution that parses its input once and shares it between both parts.
+///
+/// Every `ParsedSolution` is also a [`Solution`], so it's registered the same
+/// way through the [`Date`] derive.
+///
+/// `Params` holds values that differ between the examples and real inputs,
+/// use `()` if there are none.
+pub trait ParsedSolution: Sync + std::fmt::Debug + Date {
+    type Input<'a>;
+    type Params: FromParams;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error>;
+
+    fn solve(&self, input: &Self::Input<'_>, part: Part, params: &Self::Params) -> AocResult {
+        match part {
+            Part::One => self.part_one(input, params),
+            Part::Two => self.part_two(input, params),
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn part_one(&self, input: &Self::Input<'_>, params: &Self::Params) -> AocResult {
+        Err(Error::NotImplemented)
+    }
+
+    #[allow(unused_variables)]
+    fn part_two(&self, input: &Self::Input<'_>, params: &Self::Params) -> AocResult {
+        Err(Error::NotImplemented)
+    }
+}
+
+impl<T: ParsedSolution> Solution for T {
+    fn solve(&self, input: &str, part: Part) -> AocResult {
+        self.solve_with(input, part, &Params::new())
+    }
+
+    fn solve_with(&self, input: &str, part: Part, params: &Params) -> AocResult {
+        let params = T::Params::from_params(params)?;
+        ParsedSolution::solve(self, &self.parse(input)?, part, &params)
+    }
+
+    fn part_one(&self, input: &str) -> AocResult {
+        Solution::solve(self, input, Part::One)
+    }
+
+    fn part_two(&self, input: &str) -> AocResult {
+        Solution::solve(self, input, Part::Two)
+    }
+
+    fn run(&self, input: &str, parts: &[Part], params: &Params) -> Run {
+        let _run = tracing::info_span!("run", year = self.year(), day = self.day()).entered();
+        let (parsed, parse) = match T::Params::from_params(params) {
+            Ok(params) => {
+                let _parse = tracing::info_span!("parse").entered();
+                let (parsed, parse) = run::timed(|| panic::catch(|| self.parse(input)));
+                (parsed.map(|parsed| (parsed, params)), parse)
+            }
+            Err(err) => (Err(err), Default::default()),
+        };
+        let parts = parts
+            .iter()
+            .map(|&part| match &parsed {
+                Ok((parsed, params)) => {
+                    let _part = tracing::info_span!("solve", ?part).entered();
+                    PartRun::timed(part, || ParsedSolution::solve(self, parsed, part, params))
+                }
+                Err(err) => PartRun {
+                    part,
+                    result: Err(err.clone()),
+                    time: Default::default(),
+                    memory: None,
+                },
+            })
+            .collect();
+        Run {
+            parse: Some(parse),
+            parts,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -71,8 +209,24 @@ impl Date for NotImplemented {
 #[macro_export]
 macro_rules! assert_solution(
     ($solution:ident . $part:ident, $input:expr, $expected:expr) => {
-        assert_eq!($solution.$part($input).map(|x| x.to_string()), Ok($expected.to_string()));
-    }
+        assert_eq!(
+            $crate::Solution::$part(&$solution, $input).map(|answer| answer.to_string()),
+            Ok($expected.to_string())
+        );
+    };
+    ($solution:ident . $part:ident, $input:expr, $expected:expr, $($key:ident = $value:expr),+ $(,)?) => {
+        let part = match stringify!($part) {
+            "part_one" => $crate::Part::One,
+            "part_two" => $crate::Part::Two,
+            other => panic!("unknown part {}", other),
+        };
+        let params = $crate::Params::new()$(.with(stringify!($key), $value.to_string()))+;
+        assert_eq!(
+            $crate::Solution::solve_with(&$solution, $input, part, &params)
+                .map(|answer| answer.to_string()),
+            Ok($expected.to_string())
+        );
+    };
 );
 
 inventory::collect!(&'static dyn Solution);
@@ -0,0 +1,89 @@
+use serde::Serialize;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicBool, AtomicU64, Ordering::Relaxed},
+};
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+/// Global allocator that counts allocations on top of the system allocator,
+/// so [`PartRun`](crate::PartRun)s can report [`Memory`].
+///
+/// Counters are shared by all threads, so concurrent runs count each other's
+/// allocations too.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size() as u64, Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            LIVE.fetch_sub(layout.size() as u64, Relaxed);
+            allocated(new_size);
+        }
+        new
+    }
+}
+
+fn allocated(size: usize) {
+    INSTALLED.store(true, Relaxed);
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    ALLOCATED.fetch_add(size as u64, Relaxed);
+    let live = LIVE.fetch_add(size as u64, Relaxed) + size as u64;
+    PEAK.fetch_max(live, Relaxed);
+}
+
+/// Heap usage of one step of a run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Memory {
+    pub allocations: u64,
+    /// Total bytes allocated, including memory freed again.
+    pub allocated: u64,
+    /// Most bytes alive at once, on top of what was alive before.
+    pub peak: u64,
+}
+
+/// Runs `f` and measures its heap usage, `None` unless the
+/// [`CountingAllocator`] is installed.
+///
+/// A measurement starting on another thread meanwhile resets the peak, so
+/// runs are only measured correctly one at a time, as in `tests/memory.rs`.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Memory>) {
+    let allocations = ALLOCATIONS.load(Relaxed);
+    let allocated = ALLOCATED.load(Relaxed);
+    let live = LIVE.load(Relaxed);
+    PEAK.store(live, Relaxed);
+
+    let value = f();
+
+    let memory = INSTALLED.load(Relaxed).then(|| Memory {
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        allocated: ALLOCATED.load(Relaxed) - allocated,
+        peak: PEAK.load(Relaxed).saturating_sub(live),
+    });
+    (value, memory)
+}
@@ -0,0 +1,193 @@
+use crate::Error;
+
+/// Characters used for lit and unlit pixels of a letter grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyphs {
+    pub on: char,
+    pub off: char,
+}
+
+impl Glyphs {
+    pub const HASH: Self = Self { on: '#', off: '.' };
+    pub const BLOCK: Self = Self {
+        on: '\u{2588}',
+        off: ' ',
+    };
+}
+
+/// The 6 pixel high font, letters are 4 pixels wide except `I` and `Y`.
+const SMALL: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// The 10 pixel high font, letters are 6 pixels wide.
+#[rustfmt::skip]
+const LARGE: &[(char, &str)] = &[
+    ('A', "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#"),
+    ('B', "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####."),
+    ('C', ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####."),
+    ('E', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('F', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('G', ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#"),
+    ('H', "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#"),
+    ('J', "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n#...#.\n.###.."),
+    ('K', "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#"),
+    ('L', "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('N', "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#"),
+    ('P', "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('R', "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#"),
+    ('X', "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#"),
+    ('Z', "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######"),
+];
+
+/// Reads the capital letters drawn in `rows`, in either font, with `#` and
+/// `.` or `█` and space as pixels.
+pub fn ocr<S: AsRef<str>>(rows: &[S]) -> Result<String, Error> {
+    let glyphs = if rows
+        .iter()
+        .any(|row| row.as_ref().contains(Glyphs::BLOCK.on))
+    {
+        Glyphs::BLOCK
+    } else {
+        Glyphs::HASH
+    };
+    ocr_with(rows, glyphs)
+}
+
+/// Like [`ocr`] but with the pixels drawn as `glyphs`.
+pub fn ocr_with<S: AsRef<str>>(rows: &[S], glyphs: Glyphs) -> Result<String, Error> {
+    let font = match rows.len() {
+        6 => SMALL,
+        10 => LARGE,
+        height => {
+            return Err(Error::InvalidInput(format!(
+                "no font is {height} pixels high"
+            )))
+        }
+    };
+
+    let pixels = rows
+        .iter()
+        .map(|row| {
+            row.as_ref()
+                .chars()
+                .map(|c| match c {
+                    _ if c == glyphs.on => Ok(true),
+                    _ if c == glyphs.off => Ok(false),
+                    _ => Err(Error::InvalidInput(format!("{c:?} is not a pixel"))),
+                })
+                .collect()
+        })
+        .collect::<Result<Vec<Vec<bool>>, Error>>()?;
+
+    letters(&pixels)
+        .into_iter()
+        .map(|letter| {
+            font.iter()
+                .find(|(_, glyph)| parse_glyph(glyph) == letter)
+                .map(|&(c, _)| c)
+                .ok_or_else(|| Error::InvalidInput(format!("unknown letter\n{}", draw(&letter))))
+        })
+        .collect()
+}
+
+/// Splits `pixels` into letters at unlit columns, without those columns.
+fn letters(pixels: &[Vec<bool>]) -> Vec<Vec<Vec<bool>>> {
+    let width = pixels.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |x: usize| pixels.iter().any(|row| row.get(x) == Some(&true));
+
+    let mut letters = vec![];
+    let mut x = 0;
+    while x < width {
+        if !lit(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < width && lit(x) {
+            x += 1;
+        }
+        letters.push(
+            pixels
+                .iter()
+                .map(|row| (start..x).map(|x| row.get(x) == Some(&true)).collect())
+                .collect(),
+        );
+    }
+    letters
+}
+
+fn parse_glyph(glyph: &str) -> Vec<Vec<bool>> {
+    let pixels: Vec<Vec<bool>> = glyph
+        .lines()
+        .map(|row| row.chars().map(|c| c == '#').collect())
+        .collect();
+    letters(&pixels).pop().unwrap_or_default()
+}
+
+fn draw(letter: &[Vec<bool>]) -> String {
+    letter
+        .iter()
+        .map(|row| row.iter().map(|&on| if on { '#' } else { '.' }).collect())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small() {
+        let rows = [
+            "###..#....###...##..####.###...##..#....",
+            "#..#.#....#..#.#..#.#....#..#.#..#.#....",
+            "#..#.#....#..#.#..#.###..###..#....#....",
+            "###..#....###..####.#....#..#.#....#....",
+            "#.#..#....#.#..#..#.#....#..#.#..#.#....",
+            "#..#.####.#..#.#..#.#....###...##..####.",
+        ];
+        assert_eq!(ocr(&rows), Ok("RLRAFBCL".into()));
+
+        let blocks = rows.map(|row| row.replace('#', "█").replace('.', " "));
+        assert_eq!(ocr(&blocks), Ok("RLRAFBCL".into()));
+        assert_eq!(ocr_with(&blocks, Glyphs::BLOCK), Ok("RLRAFBCL".into()));
+    }
+
+    #[test]
+    fn large() {
+        let glyphs = |c| LARGE.iter().find(|(l, _)| *l == c).unwrap().1;
+        let rows: Vec<String> = (0..10)
+            .map(|y| {
+                ["H", "X", "N"]
+                    .map(|c| glyphs(c.chars().next().unwrap()).lines().nth(y).unwrap())
+                    .join("..")
+            })
+            .collect();
+        assert_eq!(ocr(&rows), Ok("HXN".into()));
+    }
+
+    #[test]
+    fn unknown() {
+        assert!(ocr(&["#"; 6]).is_err());
+        assert!(ocr(&["#"; 7]).is_err());
+        assert!(ocr(&["x"; 6]).is_err());
+    }
+}
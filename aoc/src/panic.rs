@@ -0,0 +1,78 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+use crate::Error;
+
+thread_local! {
+    /// How many [`catch`]es are running on this thread.
+    static CATCHING: Cell<usize> = const { Cell::new(0) };
+    /// Where the last caught panic on this thread happened.
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+/// Runs `f`, turning an unwinding panic into [`Error::Panicked`].
+///
+/// Panics caught here aren't printed, they are reported through the error
+/// instead. Panics on other threads, like those of rayon, are printed as usual
+/// and lose their location when they are passed on to this one.
+pub(crate) fn catch<T>(f: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    HOOK.call_once(install_hook);
+
+    CATCHING.with(|catching| catching.set(catching.get() + 1));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(catching.get() - 1));
+
+    result.unwrap_or_else(|payload| {
+        Err(Error::Panicked {
+            message: message(payload.as_ref()),
+            location: LOCATION.with(|location| location.take()),
+        })
+    })
+}
+
+fn install_hook() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if CATCHING.with(Cell::get) == 0 {
+            return previous(info);
+        }
+        let location = info.location().map(ToString::to_string);
+        LOCATION.with(|cell| cell.replace(location));
+    }));
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catches_panics() {
+        assert_eq!(catch(|| Ok(1)), Ok(1));
+
+        let line = line!() + 1;
+        let result: Result<(), _> = catch(|| todo!("wrap the cube"));
+        let Err(Error::Panicked { message, location }) = result else {
+            panic!("expected a panic, got {result:?}");
+        };
+        assert_eq!(message, "not yet implemented: wrap the cube");
+        assert!(location
+            .unwrap()
+            .starts_with(&format!("{}:{}:", file!(), line)));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::Error;
+
+/// Raw `key=value` puzzle parameters as given on the command line or in a
+/// test. Later values override earlier ones with the same key.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(Vec<(String, String)>);
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.0.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+}
+
+impl FromIterator<(String, String)> for Params {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// Typed parameters of a solution, starting from defaults for the real
+/// puzzle input. Usually derived with `#[derive(FromParams)]`.
+pub trait FromParams: Default + Sized {
+    /// Sets the parameter `key` from its textual `value`.
+    fn set(&mut self, key: &str, value: &str) -> Result<(), Error>;
+
+    fn from_params(params: &Params) -> Result<Self, Error> {
+        let mut typed = Self::default();
+        for (key, value) in params.iter() {
+            typed.set(key, value)?;
+        }
+        Ok(typed)
+    }
+}
+
+/// Solutions without parameters reject any that are given.
+impl FromParams for () {
+    fn set(&mut self, key: &str, _value: &str) -> Result<(), Error> {
+        Err(Error::unknown_param(key))
+    }
+}
+
+impl Error {
+    #[doc(hidden)]
+    pub fn unknown_param(key: &str) -> Self {
+        Self::InvalidInput(format!("unknown parameter {key:?}"))
+    }
+
+    #[doc(hidden)]
+    pub fn invalid_param(key: &str, value: &str, err: impl std::fmt::Display) -> Self {
+        Self::InvalidInput(format!(
+            "invalid value {value:?} for parameter {key:?}: {err}"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Limits {
+        row: i64,
+        steps: usize,
+    }
+
+    impl Default for Limits {
+        fn default() -> Self {
+            Self { row: 10, steps: 2 }
+        }
+    }
+
+    impl FromParams for Limits {
+        fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+            match key {
+                "row" => {
+                    self.row = value
+                        .parse()
+                        .map_err(|e| Error::invalid_param(key, value, e))?
+                }
+                "steps" => {
+                    self.steps = value
+                        .parse()
+                        .map_err(|e| Error::invalid_param(key, value, e))?
+                }
+                _ => return Err(Error::unknown_param(key)),
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn later_values_override() {
+        let params = Params::new().with("row", "-3").with("row", "4");
+        assert_eq!(
+            Limits::from_params(&params),
+            Ok(Limits { row: 4, steps: 2 })
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_and_invalid() {
+        let params = Params::new().with("rows", "1");
+        assert_eq!(
+            Limits::from_params(&params),
+            Err(Error::InvalidInput("unknown parameter \"rows\"".into()))
+        );
+        assert!(<()>::from_params(&params).is_err());
+        assert!(Limits::from_params(&Params::new().with("steps", "-1")).is_err());
+    }
+}
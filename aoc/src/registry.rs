@@ -0,0 +1,309 @@
+use std::{collections::BTreeMap, fmt};
+
+use crate::{function, Solution};
+
+/// First year Advent of Code took place.
+const FIRST_YEAR: usize = 2015;
+const DAYS: std::ops::RangeInclusive<usize> = 1..=25;
+
+/// Date and variant a solution is registered under. The main solution of a
+/// day, without a variant, sorts before the named ones.
+type Key = (usize, usize, Option<&'static str>);
+
+/// All solutions registered through the [`Date`](crate::Date) derive or the
+/// [`aoc`](crate::aoc) attribute, keyed and sorted by date.
+///
+/// Variants of a day are only found through [`variants`](Self::variants), the
+/// other methods are about the main solutions.
+pub struct Registry {
+    solutions: BTreeMap<Key, &'static dyn Solution>,
+    problems: Vec<Problem>,
+}
+
+/// Something wrong with the dates solutions were registered under.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// Two solutions claim the same date and variant, `first` is the one that
+    /// is used.
+    Duplicate {
+        year: usize,
+        day: usize,
+        first: String,
+        second: String,
+    },
+    /// The date isn't one Advent of Code has a puzzle for, so the solution
+    /// is left out.
+    OutOfRange {
+        year: usize,
+        day: usize,
+        solution: String,
+    },
+}
+
+impl Registry {
+    /// Collects every solution submitted to `inventory`, with the parts
+    /// registered as functions merged by day.
+    pub fn new() -> Self {
+        let (functions, problems) = function::registered();
+        let mut registry = Self::from_solutions(
+            inventory::iter::<&'static dyn Solution>
+                .into_iter()
+                .copied()
+                .chain(
+                    functions
+                        .iter()
+                        .map(|solution| solution as &'static dyn Solution),
+                ),
+        );
+        registry.problems.extend(problems.iter().cloned());
+        registry
+    }
+
+    pub fn from_solutions(solutions: impl IntoIterator<Item = &'static dyn Solution>) -> Self {
+        let mut registry = Self {
+            solutions: BTreeMap::new(),
+            problems: vec![],
+        };
+
+        for solution in solutions {
+            let (year, day) = (solution.year(), solution.day());
+            let key = (year, day, solution.variant());
+
+            if year < FIRST_YEAR || !DAYS.contains(&day) {
+                registry.problems.push(Problem::OutOfRange {
+                    year,
+                    day,
+                    solution: format!("{solution:?}"),
+                });
+                continue;
+            }
+
+            match registry.solutions.get(&key) {
+                Some(first) => registry.problems.push(Problem::Duplicate {
+                    year,
+                    day,
+                    first: format!("{first:?}"),
+                    second: format!("{solution:?}"),
+                }),
+                None => {
+                    registry.solutions.insert(key, solution);
+                }
+            }
+        }
+
+        registry
+    }
+
+    pub fn get(&self, year: usize, day: usize) -> Option<&'static dyn Solution> {
+        self.solutions.get(&(year, day, None)).copied()
+    }
+
+    /// The main solution of a day followed by its variants in order of name.
+    pub fn variants(
+        &self,
+        year: usize,
+        day: usize,
+    ) -> impl Iterator<Item = &'static dyn Solution> + '_ {
+        self.solutions
+            .range((year, day, None)..(year, day + 1, None))
+            .map(|(_, &solution)| solution)
+    }
+
+    /// The variant of a day called `name`.
+    pub fn variant(&self, year: usize, day: usize, name: &str) -> Option<&'static dyn Solution> {
+        self.variants(year, day)
+            .find(|solution| solution.variant() == Some(name))
+    }
+
+    /// All solutions, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &'static dyn Solution> + '_ {
+        self.main(..)
+    }
+
+    /// Years with at least one solution, oldest first.
+    pub fn years(&self) -> impl Iterator<Item = usize> + '_ {
+        let mut years: Vec<_> = self.iter().map(|solution| solution.year()).collect();
+        years.dedup();
+        years.into_iter()
+    }
+
+    /// Solutions of `year` by day.
+    pub fn year(&self, year: usize) -> impl Iterator<Item = &'static dyn Solution> + '_ {
+        self.main((year, 0, None)..(year + 1, 0, None))
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    fn main(
+        &self,
+        range: impl std::ops::RangeBounds<Key>,
+    ) -> impl Iterator<Item = &'static dyn Solution> + '_ {
+        self.solutions
+            .range(range)
+            .filter(|((_, _, variant), _)| variant.is_none())
+            .map(|(_, &solution)| solution)
+    }
+
+    /// Duplicate and out of range dates found while registering.
+    pub fn problems(&self) -> &[Problem] {
+        &self.problems
+    }
+
+    pub fn check(&self) -> Result<(), Vec<Problem>> {
+        if self.problems.is_empty() {
+            Ok(())
+        } else {
+            Err(self.problems.clone())
+        }
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Duplicate {
+                year,
+                day,
+                first,
+                second,
+            } => write!(
+                f,
+                "{first} and {second} are both registered for {year} day {day}"
+            ),
+            Self::OutOfRange {
+                year,
+                day,
+                solution,
+            } => write!(
+                f,
+                "{solution} is registered for {year} day {day}, which has no puzzle"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Date;
+
+    #[derive(Debug)]
+    struct Fake(usize, usize);
+
+    impl Solution for Fake {}
+
+    impl Date for Fake {
+        fn year(&self) -> usize {
+            self.0
+        }
+
+        fn day(&self) -> usize {
+            self.1
+        }
+    }
+
+    #[derive(Debug)]
+    struct Variant(&'static str);
+
+    impl Solution for Variant {}
+
+    impl Date for Variant {
+        fn year(&self) -> usize {
+            2022
+        }
+
+        fn day(&self) -> usize {
+            1
+        }
+
+        fn variant(&self) -> Option<&'static str> {
+            Some(self.0)
+        }
+    }
+
+    static SOLUTIONS: [Fake; 6] = [
+        Fake(2022, 2),
+        Fake(2021, 7),
+        Fake(2022, 1),
+        Fake(2022, 2),
+        Fake(2022, 26),
+        Fake(2014, 3),
+    ];
+
+    fn registry() -> Registry {
+        Registry::from_solutions(SOLUTIONS.iter().map(|s| s as &dyn Solution))
+    }
+
+    #[test]
+    fn sorted_by_date() {
+        let registry = registry();
+        let dates: Vec<_> = registry.iter().map(|s| (s.year(), s.day())).collect();
+        assert_eq!(dates, [(2021, 7), (2022, 1), (2022, 2)]);
+        assert_eq!(registry.years().collect::<Vec<_>>(), [2021, 2022]);
+        assert_eq!(registry.year(2022).count(), 2);
+        assert!(registry.get(2021, 7).is_some());
+        assert!(registry.get(2021, 8).is_none());
+        // Reported as problems, but never solved.
+        assert!(registry.get(2022, 26).is_none());
+        assert!(registry.get(2014, 3).is_none());
+    }
+
+    #[test]
+    fn finds_problems() {
+        assert_eq!(
+            registry().check(),
+            Err(vec![
+                Problem::Duplicate {
+                    year: 2022,
+                    day: 2,
+                    first: "Fake(2022, 2)".into(),
+                    second: "Fake(2022, 2)".into(),
+                },
+                Problem::OutOfRange {
+                    year: 2022,
+                    day: 26,
+                    solution: "Fake(2022, 26)".into(),
+                },
+                Problem::OutOfRange {
+                    year: 2014,
+                    day: 3,
+                    solution: "Fake(2014, 3)".into(),
+                },
+            ])
+        );
+    }
+
+    static VARIANTS: [Variant; 2] = [Variant("naive"), Variant("bitset")];
+
+    #[test]
+    fn keeps_variants_apart() {
+        let registry = Registry::from_solutions(
+            SOLUTIONS
+                .iter()
+                .map(|s| s as &dyn Solution)
+                .chain(VARIANTS.iter().map(|s| s as &dyn Solution)),
+        );
+        assert_eq!(registry.year(2022).count(), 2);
+        assert_eq!(
+            format!("{:?}", registry.get(2022, 1).unwrap()),
+            "Fake(2022, 1)"
+        );
+        let variants: Vec<_> = registry.variants(2022, 1).map(|s| s.variant()).collect();
+        assert_eq!(variants, [None, Some("bitset"), Some("naive")]);
+        assert!(registry.variant(2022, 1, "naive").is_some());
+        assert!(registry.variant(2022, 2, "naive").is_none());
+        assert_eq!(registry.problems().len(), 3);
+    }
+}
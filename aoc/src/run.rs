@@ -0,0 +1,39 @@
+use std::time::{Duration, Instant};
+
+use crate::{memory, panic, AocResult, Memory, Part};
+
+/// The outcome of solving one or more parts of a puzzle.
+#[derive(Debug)]
+pub struct Run {
+    /// How long parsing took, `None` if the solution parses within each part.
+    pub parse: Option<Duration>,
+    pub parts: Vec<PartRun>,
+}
+
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: Part,
+    pub result: AocResult,
+    pub time: Duration,
+    /// Heap usage, `None` unless the [`CountingAllocator`](crate::CountingAllocator) is installed.
+    pub memory: Option<Memory>,
+}
+
+impl PartRun {
+    /// Solves `part`, reporting a panic as [`Error::Panicked`](crate::Error::Panicked).
+    pub(crate) fn timed(part: Part, solve: impl FnOnce() -> AocResult) -> Self {
+        let ((result, time), memory) = memory::measure(|| timed(|| panic::catch(solve)));
+        Self {
+            part,
+            result,
+            time,
+            memory,
+        }
+    }
+}
+
+pub(crate) fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+
+/// One picture of a simulation, a rectangle of characters.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Frame {
+    rows: Vec<String>,
+}
+
+impl Frame {
+    pub fn new(rows: Vec<String>) -> Self {
+        Self { rows }
+    }
+
+    /// Builds a frame from the character at each `(x, y)`.
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        mut cell: impl FnMut(usize, usize) -> char,
+    ) -> Self {
+        Self::new(
+            (0..height)
+                .map(|y| (0..width).map(|x| cell(x, y)).collect())
+                .collect(),
+        )
+    }
+
+    pub fn rows(&self) -> &[String] {
+        &self.rows
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.rows.join("\n"))
+    }
+}
+
+/// A simulation that can be watched. Solutions pass their state to
+/// [`emit_frame`](crate::emit_frame) after every step, which only draws a
+/// frame when someone is watching.
+pub trait Visualize {
+    fn frame(&self) -> Frame;
+}
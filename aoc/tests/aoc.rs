@@ -0,0 +1,26 @@
+//! Parts registered with `#[aoc]`, named like the helpers the attribute
+//! generates.
+
+use aoc::{aoc, Error, Registry};
+
+#[aoc(year = 2021, day = 1, part = 1)]
+fn solve(input: &str) -> Result<usize, Error> {
+    Ok(input.len())
+}
+
+mod two {
+    use super::*;
+
+    #[aoc(year = 2021, day = 1, part = 2)]
+    fn solve(input: &str) -> usize {
+        input.lines().count()
+    }
+}
+
+#[test]
+fn calls_functions_named_solve() {
+    let registry = Registry::new();
+    let solution = registry.get(2021, 1).unwrap();
+    assert_eq!(solution.part_one("abc\nd"), Ok(5usize.into()));
+    assert_eq!(solution.part_two("abc\nd"), Ok(2usize.into()));
+}
@@ -0,0 +1,38 @@
+//! Allocation counters are shared by every thread of a process, so the test
+//! measuring them runs alone in a binary of its own.
+
+use aoc::*;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Debug)]
+struct Allocating;
+
+impl Date for Allocating {
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn day(&self) -> usize {
+        1
+    }
+}
+
+impl Solution for Allocating {
+    fn part_one(&self, _input: &str) -> AocResult {
+        let small = vec![0u8; 100];
+        drop(small);
+        let large = vec![0u8; 1000];
+        Ok(large.len().into())
+    }
+}
+
+#[test]
+fn measures() {
+    let run = Allocating.run("", &[Part::One], &Params::new());
+    let memory = run.parts[0].memory.unwrap();
+    assert!(memory.allocations >= 2);
+    assert!(memory.allocated >= 1100);
+    assert!(memory.peak >= 1000);
+}
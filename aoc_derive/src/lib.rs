@@ -1,30 +1,64 @@
-use darling::FromDeriveInput;
+use darling::{ast, FromDeriveInput, FromField, FromMeta};
+use std::collections::HashMap;
+
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, spanned::Spanned, AttributeArgs, DeriveInput, ItemFn, ReturnType};
 
 #[derive(FromDeriveInput)]
 #[darling(attributes(date))]
 struct DateAttributes {
     year: usize,
     day: usize,
+    variant: Option<String>,
+}
+
+/// One `#[example(...)]` on a solution: an inline `input` or a `file`
+/// relative to the crate root, the expected answers, and parameters.
+#[derive(FromMeta)]
+struct ExampleArgs {
+    name: Option<String>,
+    input: Option<String>,
+    file: Option<String>,
+    part1: Option<String>,
+    part2: Option<String>,
+    params: Option<HashMap<String, String>>,
 }
 
-#[proc_macro_derive(Date, attributes(date))]
+/// Registers a solution under its `#[date(year = .., day = ..)]`, as an
+/// alternative to the main solution of the day with `variant = ".."`.
+///
+/// Every `#[example(input = "..", part1 = "..", part2 = "..")]` on it becomes
+/// a test per part in a `{solution}_examples` module, `file = ".."` reads the
+/// input from a file instead and `params(key = "value")` overrides puzzle
+/// parameters.
+#[proc_macro_derive(Date, attributes(date, example))]
 pub fn derive_date_solution(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
-    let DateAttributes { year, day } = match FromDeriveInput::from_derive_input(&input) {
+    let DateAttributes { year, day, variant } = match FromDeriveInput::from_derive_input(&input) {
         Ok(v) => v,
         Err(e) => return e.write_errors().into(),
     };
 
     let ident = input.ident;
+    let examples = match examples(&ident, &input.attrs) {
+        Ok(examples) => examples,
+        Err(e) => return e.write_errors().into(),
+    };
+
+    let variant = variant.map(|variant| {
+        quote! {
+            fn variant(&self) -> ::std::option::Option<&'static str> {
+                ::std::option::Option::Some(#variant)
+            }
+        }
+    });
 
     let result = quote! {
-        inventory::submit!(&#ident as &dyn Solution);
+        ::aoc::inventory::submit!(&#ident as &dyn ::aoc::Solution);
 
-        impl Date for #ident {
+        impl ::aoc::Date for #ident {
             fn year(&self) -> usize {
                 #year
             }
@@ -32,8 +66,262 @@ pub fn derive_date_solution(input: TokenStream) -> TokenStream {
             fn day(&self) -> usize {
                 #day
             }
+
+            #variant
+        }
+
+        #examples
+    };
+
+    result.into()
+}
+
+fn examples(
+    ident: &syn::Ident,
+    attrs: &[syn::Attribute],
+) -> darling::Result<proc_macro2::TokenStream> {
+    let mut tests = vec![];
+    let mut errors = darling::Error::accumulator();
+
+    let attrs = attrs.iter().filter(|attr| attr.path.is_ident("example"));
+    for (i, attr) in attrs.enumerate() {
+        let Some(example) = errors.handle(example_args(attr)) else {
+            continue;
+        };
+
+        let input = match (&example.input, &example.file) {
+            (Some(input), None) => quote! { #input },
+            (None, Some(file)) => quote! {
+                ::std::include_str!(::std::concat!(::std::env!("CARGO_MANIFEST_DIR"), "/", #file))
+            },
+            _ => {
+                errors.push(
+                    darling::Error::custom("expected either `input` or `file`").with_span(attr),
+                );
+                continue;
+            }
+        };
+        let params = example
+            .params
+            .iter()
+            .flatten()
+            .map(|(key, value)| quote! { .with(#key, #value) });
+        let name = example.name.unwrap_or_else(|| format!("example_{}", i + 1));
+
+        for (part, expected, suffix) in [
+            (quote! { ::aoc::Part::One }, &example.part1, "part_one"),
+            (quote! { ::aoc::Part::Two }, &example.part2, "part_two"),
+        ] {
+            let Some(expected) = expected else {
+                continue;
+            };
+            let test = format_ident!("{}_{}", name, suffix);
+            let params = params.clone();
+            tests.push(quote! {
+                #[test]
+                fn #test() {
+                    let params = ::aoc::Params::new()#(#params)*;
+                    if let ::std::result::Result::Err(message) =
+                        ::aoc::check_example(&super::#ident, #input, #part, &params, #expected)
+                    {
+                        ::std::panic!("{}", message);
+                    }
+                }
+            });
+        }
+    }
+    errors.finish()?;
+
+    if tests.is_empty() {
+        return Ok(quote! {});
+    }
+    let module = format_ident!("{}_examples", snake_case(&ident.to_string()));
+    Ok(quote! {
+        #[cfg(test)]
+        mod #module {
+            #(#tests)*
+        }
+    })
+}
+
+fn example_args(attr: &syn::Attribute) -> darling::Result<ExampleArgs> {
+    let meta = attr.parse_meta()?;
+    ExampleArgs::from_meta(&meta).map_err(|e| e.with_span(&meta))
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+#[derive(FromField)]
+#[darling(attributes(param))]
+struct ParamField {
+    ident: Option<syn::Ident>,
+    ty: syn::Type,
+    default: Option<syn::Expr>,
+}
+
+#[derive(FromDeriveInput)]
+#[darling(attributes(param), supports(struct_named))]
+struct ParamsAttributes {
+    ident: syn::Ident,
+    generics: syn::Generics,
+    data: ast::Data<(), ParamField>,
+}
+
+/// Implements `Default` and `aoc::FromParams` for a struct of puzzle
+/// parameters, each settable by its field name. Defaults are given as
+/// `#[param(default = "expr")]`.
+#[proc_macro_derive(FromParams, attributes(param))]
+pub fn derive_from_params(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let ParamsAttributes {
+        ident,
+        generics,
+        data,
+    } = match FromDeriveInput::from_derive_input(&input) {
+        Ok(v) => v,
+        Err(e) => return e.write_errors().into(),
+    };
+
+    let fields = data.take_struct().unwrap().fields;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let defaults = fields.iter().map(|field| {
+        let name = &field.ident;
+        match &field.default {
+            Some(default) => quote! { #name: #default },
+            None => quote! { #name: ::std::default::Default::default() },
+        }
+    });
+
+    let setters = fields.iter().map(|field| {
+        let name = &field.ident;
+        let ty = &field.ty;
+        let key = name.as_ref().unwrap().to_string();
+        quote! {
+            #key => {
+                self.#name = value
+                    .parse::<#ty>()
+                    .map_err(|err| ::aoc::Error::invalid_param(key, value, err))?;
+            }
+        }
+    });
+
+    let result = quote! {
+        impl #impl_generics ::std::default::Default for #ident #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#defaults,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::aoc::FromParams for #ident #ty_generics #where_clause {
+            fn set(&mut self, key: &str, value: &str) -> ::std::result::Result<(), ::aoc::Error> {
+                match key {
+                    #(#setters)*
+                    _ => return ::std::result::Result::Err(::aoc::Error::unknown_param(key)),
+                }
+                ::std::result::Result::Ok(())
+            }
+        }
+    };
+
+    result.into()
+}
+
+#[derive(FromMeta)]
+struct AocArgs {
+    year: usize,
+    day: usize,
+    part: u8,
+}
+
+/// Registers `fn(&str) -> T` as one part of the solution of a day, where `T`
+/// is `impl Into<aoc::Answer>` or a `Result` of one with an error convertible
+/// to `aoc::Error`. Parts of the same day are merged by the `Registry`.
+///
+/// ```ignore
+/// #[aoc(year = 2021, day = 1, part = 1)]
+/// fn part_one(input: &str) -> usize {
+///     input.lines().count()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn aoc(args: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as AttributeArgs);
+    let function = parse_macro_input!(item as ItemFn);
+
+    let AocArgs { year, day, part } = match AocArgs::from_list(&args) {
+        Ok(v) => v,
+        Err(e) => return e.write_errors().into(),
+    };
+    let part = match part {
+        1 => quote! { ::aoc::Part::One },
+        2 => quote! { ::aoc::Part::Two },
+        _ => {
+            let span = args
+                .iter()
+                .find(|arg| matches!(arg, syn::NestedMeta::Meta(meta) if meta.path().is_ident("part")))
+                .map_or_else(proc_macro2::Span::call_site, |arg| arg.span());
+            return syn::Error::new(span, "part must be 1 or 2")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let ident = &function.sig.ident;
+    let answer = if returns_result(&function.sig.output) {
+        quote! {
+            #ident(input)
+                .map(::std::convert::Into::<::aoc::Answer>::into)
+                .map_err(::std::convert::Into::<::aoc::Error>::into)
+        }
+    } else {
+        quote! {
+            ::std::result::Result::Ok(::std::convert::Into::<::aoc::Answer>::into(#ident(input)))
+        }
+    };
+
+    let result = quote! {
+        #function
+
+        ::aoc::inventory::submit! {
+            ::aoc::PartFn {
+                year: #year,
+                day: #day,
+                part: #part,
+                name: ::std::concat!(::std::module_path!(), "::", ::std::stringify!(#ident)),
+                // A closure rather than a helper function, which would shadow
+                // a part function of the same name.
+                solve: |input: &str| -> ::aoc::AocResult { #answer },
+            }
         }
     };
 
     result.into()
 }
+
+/// Whether a function returns a `Result`, going by the name of the type since
+/// macros can't see through aliases other than `AocResult`.
+fn returns_result(output: &ReturnType) -> bool {
+    let ReturnType::Type(_, ty) = output else {
+        return false;
+    };
+    let syn::Type::Path(path) = &**ty else {
+        return false;
+    };
+    path.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "Result" || segment.ident == "AocResult")
+}
@@ -0,0 +1,105 @@
+//! Generates the module tree of all solutions, so that creating
+//! `src/year_YYYY/dayDD.rs` is enough to compile and register a day, and a
+//! test for every example in `examples/YYYY/DD/N.txt`.
+
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    println!("cargo:rerun-if-changed={}", src.display());
+
+    let mut years = vec![];
+    for entry in std::fs::read_dir(&src).unwrap() {
+        let path = entry.unwrap().path();
+        let Some(year) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("year_"))
+            .filter(|year| year.parse::<u32>().is_ok())
+        else {
+            continue;
+        };
+
+        let mut days = vec![];
+        for entry in std::fs::read_dir(&path).unwrap() {
+            let path = entry.unwrap().path();
+            let Some(day) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_prefix("day"))
+                .and_then(|name| name.strip_suffix(".rs"))
+                .filter(|day| day.len() == 2 && day.parse::<u32>().is_ok())
+            else {
+                continue;
+            };
+            days.push((day.to_string(), path.clone()));
+        }
+        days.sort();
+        years.push((year.to_string(), days));
+    }
+    years.sort();
+
+    let mut modules = String::new();
+    for (year, days) in years {
+        writeln!(modules, "mod year_{year} {{").unwrap();
+        for (day, path) in days {
+            writeln!(modules, "    #[path = {:?}]", path.display().to_string()).unwrap();
+            writeln!(modules, "    pub mod day{day};").unwrap();
+        }
+        writeln!(modules, "}}").unwrap();
+    }
+
+    let out = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    std::fs::write(out.join("days.rs"), modules).unwrap();
+    std::fs::write(out.join("examples.rs"), examples()).unwrap();
+}
+
+/// Numbered entries of a directory in order, such as years or days, with
+/// `suffix` stripped from their names.
+fn numbered(dir: &Path, suffix: &str) -> Vec<(u32, PathBuf)> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![];
+    };
+    let mut numbered: Vec<_> = entries
+        .map(|entry| entry.unwrap().path())
+        .filter_map(|path| {
+            let n = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(suffix))
+                .and_then(|n| n.parse().ok())?;
+            Some((n, path))
+        })
+        .collect();
+    numbered.sort();
+    numbered
+}
+
+fn examples() -> String {
+    let examples = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    println!("cargo:rerun-if-changed={}", examples.display());
+
+    let mut tests = String::new();
+    for (year, path) in numbered(&examples, "") {
+        writeln!(tests, "mod year_{year} {{").unwrap();
+        for (day, path) in numbered(&path, "") {
+            writeln!(tests, "    mod day{day:02} {{").unwrap();
+            for (n, _) in numbered(&path, ".txt") {
+                writeln!(tests, "        #[test]").unwrap();
+                writeln!(tests, "        fn example_{n}() {{").unwrap();
+                writeln!(
+                    tests,
+                    "            super::super::check({year}, {day}, {n});"
+                )
+                .unwrap();
+                writeln!(tests, "        }}").unwrap();
+            }
+            writeln!(tests, "    }}").unwrap();
+        }
+        writeln!(tests, "}}").unwrap();
+    }
+    tests
+}
@@ -0,0 +1,224 @@
+use std::{
+    collections::BTreeMap,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use aoc::{Answer, Part};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::client::Verdict;
+
+/// Known correct answers for the real inputs of one year, stored as
+/// `{answers}/{year}.json`.
+#[derive(Debug)]
+pub struct Answers {
+    path: PathBuf,
+    days: BTreeMap<usize, DayAnswers>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    one: Option<Known>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    two: Option<Known>,
+}
+
+/// An answer kept either as is or as a salted hash that doesn't spoil it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Known {
+    Plain(Answer),
+    Hashed { salt: String, sha256: String },
+}
+
+impl Known {
+    pub fn hashed(answer: &Answer) -> Self {
+        let salt = format!("{:016x}", rand::thread_rng().gen::<u64>());
+        let sha256 = hash(&salt, answer);
+        Self::Hashed { salt, sha256 }
+    }
+
+    /// Compares answers as printed, as hashes do, so the variant an answer was
+    /// returned as doesn't matter.
+    pub fn matches(&self, answer: &Answer) -> bool {
+        match self {
+            Self::Plain(known) => known.to_string() == answer.to_string(),
+            Self::Hashed { salt, sha256 } => hash(salt, answer) == *sha256,
+        }
+    }
+}
+
+fn hash(salt: &str, answer: &Answer) -> String {
+    let digest = Sha256::new()
+        .chain_update(salt)
+        .chain_update(answer.to_string())
+        .finalize();
+    digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+impl Answers {
+    /// Loads the answers of `year`, starting out empty if there are none yet.
+    pub fn load(dir: &Path, year: usize) -> io::Result<Self> {
+        let path = dir.join(format!("{year}.json"));
+        let days = match std::fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err),
+        };
+        Ok(Self { path, days })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(&self.days)?;
+        std::fs::write(&self.path, json + "\n")
+    }
+
+    pub fn get(&self, day: usize, part: Part) -> Option<&Known> {
+        let answers = self.days.get(&day)?;
+        match part {
+            Part::One => answers.one.as_ref(),
+            Part::Two => answers.two.as_ref(),
+        }
+    }
+
+    pub fn insert(&mut self, day: usize, part: Part, known: Known) {
+        let answers = self.days.entry(day).or_default();
+        match part {
+            Part::One => answers.one = Some(known),
+            Part::Two => answers.two = Some(known),
+        }
+    }
+}
+
+/// Every answer submitted for one year with the verdict it got, kept next to
+/// the inputs as `{inputs}/{year}/submissions.jsonl`.
+#[derive(Debug)]
+pub struct SubmissionLog {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: usize,
+    pub part: u8,
+    pub answer: Answer,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+}
+
+impl SubmissionLog {
+    pub fn load(inputs: &Path, year: usize) -> io::Result<Self> {
+        let path = inputs.join(year.to_string()).join("submissions.jsonl");
+        let submissions = match std::fs::read_to_string(&path) {
+            Ok(log) => log
+                .lines()
+                .map(serde_json::from_str)
+                .collect::<Result<_, _>>()?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+            Err(err) => return Err(err),
+        };
+        Ok(Self { path, submissions })
+    }
+
+    pub fn append(&mut self, submission: Submission) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(&submission)?)?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    /// Why `answer` can't be right according to earlier verdicts, if it can't.
+    pub fn refusal(&self, day: usize, part: Part, answer: &Answer) -> Option<String> {
+        let part = part_number(part);
+        self.submissions
+            .iter()
+            .filter(|submission| submission.day == day && submission.part == part)
+            .find_map(|submission| {
+                let earlier = &submission.answer;
+                match submission.verdict {
+                    Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow if earlier == answer => {
+                        Some(format!("{} was already {}", answer, submission.verdict))
+                    }
+                    Verdict::TooHigh if is_integer(answer) && answer > earlier => Some(format!(
+                        "{} is higher than {}, which was too high",
+                        answer, earlier
+                    )),
+                    Verdict::TooLow if is_integer(answer) && answer < earlier => Some(format!(
+                        "{} is lower than {}, which was too low",
+                        answer, earlier
+                    )),
+                    _ => None,
+                }
+            })
+    }
+}
+
+fn is_integer(answer: &Answer) -> bool {
+    matches!(
+        answer,
+        Answer::Unsigned(_) | Answer::Signed(_) | Answer::Big(_)
+    )
+}
+
+pub fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+#[test]
+fn known_answers() {
+    let answer = Answer::from(1234u32);
+    let hashed = Known::hashed(&answer);
+    assert!(hashed.matches(&Answer::from(1234i64)));
+    assert!(!hashed.matches(&Answer::from(1235u32)));
+
+    let json = serde_json::to_string(&hashed).unwrap();
+    let parsed: Known = serde_json::from_str(&json).unwrap();
+    assert!(parsed.matches(&answer));
+
+    let plain: Known = serde_json::from_str(r#"{"unsigned":1234}"#).unwrap();
+    assert!(plain.matches(&answer));
+}
+
+#[test]
+fn refuses_ruled_out_answers() {
+    let dir = std::env::temp_dir().join(format!("aoc-log-{}", std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+
+    let mut log = SubmissionLog::load(&dir, 2022).unwrap();
+    for (answer, verdict) in [(100u32, Verdict::TooHigh), (10, Verdict::TooLow)] {
+        log.append(Submission {
+            day: 3,
+            part: 1,
+            answer: answer.into(),
+            verdict,
+            time: 0,
+        })
+        .unwrap();
+    }
+
+    let log = SubmissionLog::load(&dir, 2022).unwrap();
+    assert!(log.refusal(3, Part::One, &Answer::from(100u32)).is_some());
+    assert!(log.refusal(3, Part::One, &Answer::from(101u32)).is_some());
+    assert!(log.refusal(3, Part::One, &Answer::from(9u32)).is_some());
+    assert!(log.refusal(3, Part::One, &Answer::from(50u32)).is_none());
+    assert!(log.refusal(3, Part::Two, &Answer::from(100u32)).is_none());
+    std::fs::remove_dir_all(dir).ok();
+}
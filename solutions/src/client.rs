@@ -0,0 +1,406 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc::{Answer, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::input_path;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/lukad/adventofcode";
+
+/// Talks to the puzzle server on behalf of a logged in user, at most once
+/// per `throttle`. Inputs are kept in `cache` and never downloaded twice.
+pub struct Client {
+    base_url: String,
+    session: String,
+    cache: PathBuf,
+    throttle: Duration,
+    agent: ureq::Agent,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+    /// The puzzle unlocks at the given time.
+    Locked(SystemTime),
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, cache: &Path, throttle: Duration) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            cache: cache.to_path_buf(),
+            throttle,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Downloads the input of a puzzle unless it's cached or still locked.
+    pub fn fetch(&self, year: usize, day: usize) -> Result<Fetched, String> {
+        self.fetch_at(year, day, SystemTime::now())
+    }
+
+    fn fetch_at(&self, year: usize, day: usize, now: SystemTime) -> Result<Fetched, String> {
+        let path = input_path(&self.cache, year, day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let unlock = unlock_time(year, day);
+        if now < unlock {
+            return Ok(Fetched::Locked(unlock));
+        }
+
+        let input = self.get(&format!("/{year}/day/{day}/input"))?;
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+        }
+        std::fs::write(&path, input).map_err(|err| err.to_string())?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        self.wait();
+        let response = self
+            .agent
+            .get(&format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        self.requested();
+        read(response)
+    }
+
+    /// Posts the answer to a part and reads the verdict off the response.
+    pub fn submit(
+        &self,
+        year: usize,
+        day: usize,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<Verdict, String> {
+        let level = match part {
+            Part::One => "1",
+            Part::Two => "2",
+        };
+        let page = self.post(
+            &format!("/{year}/day/{day}/answer"),
+            &[("level", level), ("answer", &answer.to_string())],
+        )?;
+        Ok(Verdict::parse(&page))
+    }
+
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        self.wait();
+        let response = self
+            .agent
+            .post(&format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(form);
+        self.requested();
+        read(response)
+    }
+
+    /// Time of the last request is kept in the cache, so the throttle holds
+    /// across runs too.
+    fn last_request_path(&self) -> PathBuf {
+        self.cache.join(".last-request")
+    }
+
+    fn wait(&self) {
+        let last = std::fs::read_to_string(self.last_request_path())
+            .ok()
+            .and_then(|secs| secs.trim().parse().ok())
+            .map(|secs| UNIX_EPOCH + Duration::from_secs(secs));
+        if let Some(elapsed) = last.and_then(|last| last.elapsed().ok()) {
+            if let Some(remaining) = self.throttle.checked_sub(elapsed) {
+                std::thread::sleep(remaining);
+            }
+        }
+    }
+
+    fn requested(&self) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        std::fs::create_dir_all(&self.cache).ok();
+        std::fs::write(self.last_request_path(), now.as_secs().to_string()).ok();
+    }
+}
+
+/// What the server made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Answered too recently, try again after `wait` seconds if known.
+    RateLimited {
+        wait: Option<u64>,
+    },
+    /// The part was solved before, so the answer wasn't checked.
+    AlreadySolved,
+    /// The page didn't say anything we recognize, keeps its text.
+    Unknown(String),
+}
+
+impl Verdict {
+    pub fn parse(page: &str) -> Self {
+        let text = page
+            .split_once("<article>")
+            .and_then(|(_, article)| article.split_once("</article>"))
+            .map_or(page, |(article, _)| article);
+        let text = strip_tags(text);
+
+        if text.contains("That's the right answer") {
+            Self::Correct
+        } else if text.contains("You gave an answer too recently") {
+            Self::RateLimited {
+                wait: parse_wait(&text),
+            }
+        } else if text.contains("your answer is too high") {
+            Self::TooHigh
+        } else if text.contains("your answer is too low") {
+            Self::TooLow
+        } else if text.contains("That's not the right answer") {
+            Self::Wrong
+        } else if text.contains("You don't seem to be solving the right level") {
+            Self::AlreadySolved
+        } else {
+            Self::Unknown(text.split_whitespace().collect::<Vec<_>>().join(" "))
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => f.write_str("correct"),
+            Self::TooHigh => f.write_str("too high"),
+            Self::TooLow => f.write_str("too low"),
+            Self::Wrong => f.write_str("wrong"),
+            Self::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, wait {}s", wait)
+            }
+            Self::RateLimited { wait: None } => f.write_str("rate limited"),
+            Self::AlreadySolved => f.write_str("already solved"),
+            Self::Unknown(text) => write!(f, "unknown verdict: {}", text),
+        }
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text
+}
+
+/// Reads waits like `You have 1m 5s left to wait`.
+fn parse_wait(text: &str) -> Option<u64> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+fn read(response: Result<ureq::Response, ureq::Error>) -> Result<String, String> {
+    match response {
+        Ok(response) => response.into_string().map_err(|err| err.to_string()),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(format!("server answered {}: {}", status, body.trim()))
+        }
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// Puzzles unlock at midnight UTC-5 on their day of December.
+pub fn unlock_time(year: usize, day: usize) -> SystemTime {
+    // Days since the epoch of December 1st, from Howard Hinnant's
+    // `days_from_civil`.
+    let (y, m, d) = (year as i64, 12, 1);
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (m - 3) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468 + day as i64 - 1;
+
+    UNIX_EPOCH + Duration::from_secs((days * 86400 + 5 * 3600) as u64)
+}
+
+/// Session token from `AOC_SESSION` or the `adventofcode/session` file in
+/// the config directory.
+pub fn session(explicit: Option<String>) -> Result<String, String> {
+    if let Some(session) = explicit {
+        return Ok(session);
+    }
+    let path = config_dir()
+        .map(|dir| dir.join("adventofcode").join("session"))
+        .ok_or("no session token given and no config directory")?;
+    std::fs::read_to_string(&path)
+        .map(|session| session.trim().to_string())
+        .map_err(|err| format!("no session token given and {}: {}", path.display(), err))
+}
+
+fn config_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::mpsc,
+    };
+
+    use super::*;
+
+    /// Serves `responses` in order on a local port, sending each request line
+    /// and body back through the returned channel.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        std::thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if request.is_empty() || line.starts_with("Cookie") {
+                        request += &line;
+                    }
+                }
+                let mut content = vec![0; length];
+                std::io::Read::read_exact(&mut reader, &mut content).unwrap();
+                request += &String::from_utf8(content).unwrap();
+                sender.send(request).unwrap();
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        (url, receiver)
+    }
+
+    pub fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        std::fs::remove_dir_all(&dir).ok();
+        dir
+    }
+
+    #[test]
+    fn unlocks_at_midnight_est() {
+        let unlock = unlock_time(2022, 1).duration_since(UNIX_EPOCH).unwrap();
+        // 2022-12-01T05:00:00Z
+        assert_eq!(unlock.as_secs(), 1669870800);
+        let unlock = unlock_time(2023, 25).duration_since(UNIX_EPOCH).unwrap();
+        // 2023-12-25T05:00:00Z
+        assert_eq!(unlock.as_secs(), 1703480400);
+    }
+
+    #[test]
+    fn fetches_once() {
+        let (url, requests) = serve(vec![(200, "1\n2\n")]);
+        let cache = temp_dir("fetch");
+        let client = Client::new(&url, "secret", &cache, Duration::ZERO);
+        let path = input_path(&cache, 2022, 3);
+
+        let before_unlock = unlock_time(2022, 3) - Duration::from_secs(1);
+        assert_eq!(
+            client.fetch_at(2022, 3, before_unlock),
+            Ok(Fetched::Locked(unlock_time(2022, 3)))
+        );
+        assert_eq!(client.fetch(2022, 3), Ok(Fetched::Downloaded(path.clone())));
+        assert_eq!(client.fetch(2022, 3), Ok(Fetched::Cached(path.clone())));
+
+        assert_eq!(
+            requests.recv().unwrap(),
+            "GET /2022/day/3/input HTTP/1.1\r\nCookie: session=secret\r\n"
+        );
+        assert_eq!(std::fs::read_to_string(path).unwrap(), "1\n2\n");
+        std::fs::remove_dir_all(cache).ok();
+    }
+
+    #[test]
+    fn submits() {
+        let (url, requests) = serve(vec![
+            (
+                200,
+                "<main><article><p>That's not the right answer; your answer is too high. \
+                 <a href=\"/2022/day/3\">[Return to Day 3]</a></p></article></main>",
+            ),
+            (
+                200,
+                "<article><p>You gave an answer too recently. You have 1m 5s left to wait.</p></article>",
+            ),
+            (200, "<article><p>That's the right answer! You are one gold star closer.</p></article>"),
+        ]);
+        let cache = temp_dir("submit");
+        let client = Client::new(&url, "secret", &cache, Duration::ZERO);
+        let answer = Answer::from(42u32);
+
+        assert_eq!(
+            client.submit(2022, 3, Part::Two, &answer),
+            Ok(Verdict::TooHigh)
+        );
+        assert_eq!(
+            requests.recv().unwrap(),
+            "POST /2022/day/3/answer HTTP/1.1\r\nCookie: session=secret\r\nlevel=2&answer=42"
+        );
+        assert_eq!(
+            client.submit(2022, 3, Part::Two, &answer),
+            Ok(Verdict::RateLimited { wait: Some(65) })
+        );
+        assert_eq!(
+            client.submit(2022, 3, Part::Two, &answer),
+            Ok(Verdict::Correct)
+        );
+        std::fs::remove_dir_all(cache).ok();
+    }
+}
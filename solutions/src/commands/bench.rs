@@ -0,0 +1,177 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use aoc::{Bench, Error, Measurement, Params, Registry, Stats};
+use clap::Args;
+
+use super::{input_path, parse_param, parse_seconds, Selection};
+use crate::table::Table;
+
+/// Benchmark results keyed by `{year}/{day:02}`.
+type Results = BTreeMap<String, Measurement>;
+
+/// How often and how long to run each solution.
+#[derive(Debug, Args)]
+pub struct Timing {
+    /// Runs before timing starts
+    #[clap(long, default_value_t = 3)]
+    warmup: usize,
+    /// Number of timed runs, instead of running until the budget is used up
+    #[clap(short = 'n', long)]
+    iterations: Option<usize>,
+    /// Seconds to spend timing each day
+    #[clap(long, default_value_t = 1.0, value_parser = parse_seconds)]
+    budget: f64,
+}
+
+impl Timing {
+    pub fn bench(&self) -> Bench {
+        Bench {
+            warmup: self.warmup,
+            iterations: self.iterations,
+            budget: Duration::from_secs_f64(self.budget),
+        }
+    }
+}
+
+/// Times parsing and solving of the selected solutions over many runs.
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    #[clap(flatten)]
+    selection: Selection,
+    /// Time this alternative implementation of each day, skipping days
+    /// without it
+    #[clap(long)]
+    variant: Option<String>,
+    /// Directory containing inputs as `{year}/{day:02}.txt`
+    #[clap(long, default_value = "inputs")]
+    inputs: PathBuf,
+    #[clap(flatten)]
+    timing: Timing,
+    /// Write the results as JSON to this file
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+    /// Compare medians with results previously written by `--output`
+    #[clap(long)]
+    baseline: Option<PathBuf>,
+    /// Percentage by which a median may be slower than the baseline
+    #[clap(long, default_value_t = 10.0)]
+    threshold: f64,
+    /// Puzzle parameter overriding the default for real inputs, may be repeated
+    #[clap(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+}
+
+pub fn bench(args: BenchArgs, registry: &Registry) -> ExitCode {
+    let baseline: Results = match &args.baseline {
+        Some(path) => match read_results(path) {
+            Ok(baseline) => baseline,
+            Err(err) => {
+                eprintln!("Could not read baseline {}: {}", path.display(), err);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Results::new(),
+    };
+
+    let bench = args.timing.bench();
+    let params: Params = args.params.into_iter().collect();
+
+    let mut table = Table::new([
+        "Year", "Day", "Step", "Samples", "Min", "Median", "Mean", "Stddev", "Change",
+    ]);
+    let mut results = Results::new();
+    let mut failed = false;
+
+    for solution in args.selection.variants(registry, args.variant.as_deref()) {
+        let (year, day) = (solution.year(), solution.day());
+        let key = format!("{year}/{day:02}");
+
+        let Ok(input) = std::fs::read_to_string(input_path(&args.inputs, year, day)) else {
+            continue;
+        };
+
+        let measurement = match bench.run(solution, &input, &params) {
+            Ok(measurement) => measurement,
+            Err(Error::NotImplemented) => continue,
+            Err(err) => {
+                eprintln!("{} day {}: {}", year, day, err);
+                failed = true;
+                continue;
+            }
+        };
+
+        let before = baseline.get(&key);
+        let steps = [
+            ("parse", measurement.parse, before.and_then(|m| m.parse)),
+            ("one", measurement.one, before.and_then(|m| m.one)),
+            ("two", measurement.two, before.and_then(|m| m.two)),
+        ];
+        for (step, stats, before) in steps {
+            let Some(stats) = stats else {
+                continue;
+            };
+
+            let change = match before {
+                Some(before) => {
+                    let change = compare(&stats, &before);
+                    if change > args.threshold {
+                        failed = true;
+                        format!("{change:+.1}% slower")
+                    } else {
+                        format!("{change:+.1}%")
+                    }
+                }
+                None => String::new(),
+            };
+
+            table.push([
+                year.to_string(),
+                day.to_string(),
+                step.to_string(),
+                stats.samples.to_string(),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.stddev),
+                change,
+            ]);
+        }
+
+        results.insert(key, measurement);
+    }
+
+    print!("{}", table);
+
+    if let Some(path) = &args.output {
+        let json = serde_json::to_string_pretty(&results).unwrap();
+        if let Err(err) = std::fs::write(path, json + "\n") {
+            eprintln!("Could not write {}: {}", path.display(), err);
+            failed = true;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn read_results(path: &Path) -> std::io::Result<Results> {
+    let json = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// Change of the median in percent, positive if `stats` is slower.
+fn compare(stats: &Stats, before: &Stats) -> f64 {
+    let before = before.median.as_secs_f64();
+    if before == 0.0 {
+        return 0.0;
+    }
+    (stats.median.as_secs_f64() / before - 1.0) * 100.0
+}
@@ -0,0 +1,171 @@
+use std::{path::PathBuf, process::ExitCode, time::Duration};
+
+use aoc::{Answer, Error, Params, Part, Registry};
+use clap::Args;
+
+use super::{bench::Timing, input_path, parse_param, parse_seconds, run_solution, variant_name};
+use crate::{
+    examples::{self, Example},
+    report,
+    table::Table,
+};
+
+/// Runs every variant of a day on the same input, checks that they agree and
+/// benchmarks them against each other.
+#[derive(Debug, Args)]
+pub struct CrosscheckArgs {
+    #[clap(short, long)]
+    year: usize,
+    #[clap(short, long)]
+    day: usize,
+    /// Directory containing inputs as `{year}/{day:02}.txt`
+    #[clap(long, default_value = "inputs")]
+    inputs: PathBuf,
+    /// Use the Nth example of the day instead of the input
+    #[clap(long, value_name = "N")]
+    example: Option<usize>,
+    /// Directory containing examples as `{year}/{day:02}/{n}.txt`
+    #[clap(long, value_name = "DIR", default_value = examples::DEFAULT_DIR)]
+    examples: PathBuf,
+    /// Only compare the answers, without benchmarking
+    #[clap(long)]
+    no_bench: bool,
+    #[clap(flatten)]
+    timing: Timing,
+    /// Give up on a variant after this many seconds
+    #[clap(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<f64>,
+    /// Puzzle parameter overriding the default for real inputs, may be repeated
+    #[clap(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+}
+
+pub fn crosscheck(args: CrosscheckArgs, registry: &Registry) -> ExitCode {
+    let variants: Vec<_> = registry.variants(args.year, args.day).collect();
+    if variants.is_empty() {
+        eprintln!("No solution for {} day {}", args.year, args.day);
+        return ExitCode::FAILURE;
+    }
+
+    let (input, params) = match args.example {
+        Some(n) => match Example::load(&args.examples, args.year, args.day, n) {
+            Ok(example) => {
+                let params = example.params().chain(args.params).collect();
+                (example.input, params)
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => {
+            let path = input_path(&args.inputs, args.year, args.day);
+            match std::fs::read_to_string(&path) {
+                Ok(input) => (input, args.params.into_iter().collect::<Params>()),
+                Err(err) => {
+                    eprintln!("Could not read {}: {}", path.display(), err);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+
+    let mut failed = false;
+    // The first answer to each part, which the others have to agree with.
+    let mut agreed: [Option<(&str, Answer)>; 2] = [None, None];
+    let mut answers = vec![];
+    for &solution in &variants {
+        let name = variant_name(solution);
+        let run = run_solution(
+            solution,
+            input.clone(),
+            &[Part::One, Part::Two],
+            params.clone(),
+            args.timeout,
+        );
+
+        let mut cells = vec![];
+        let mut solved = true;
+        for part in run.parts {
+            let agreed = &mut agreed[part.part as usize];
+            match part.result {
+                Ok(answer) => {
+                    cells.push(report::one_line(&report::answer_text(&answer, false)));
+                    match agreed {
+                        Some((first, expected)) if *expected != answer => {
+                            eprintln!(
+                                "Part {:?}: {} answered {}, but {} answered {}",
+                                part.part, name, answer, first, expected
+                            );
+                            failed = true;
+                        }
+                        Some(_) => (),
+                        None => *agreed = Some((name, answer)),
+                    }
+                }
+                Err(Error::NotImplemented) => cells.push(String::new()),
+                Err(err) => {
+                    eprintln!("Part {:?} of {}: {}", part.part, name, err);
+                    cells.push("error".into());
+                    solved = false;
+                    failed = true;
+                }
+            }
+        }
+        answers.push((cells, solved));
+    }
+
+    let mut header = vec!["Variant", "Part One", "Part Two"];
+    if !args.no_bench {
+        header.extend(["Parse", "One", "Two", "Relative"]);
+    }
+    let mut table = Table::new(header);
+    let bench = args.timing.bench();
+    let mut baseline = None;
+    for (&solution, (cells, solved)) in variants.iter().zip(answers) {
+        let mut row = vec![variant_name(solution).to_string()];
+        row.extend(cells);
+
+        if args.no_bench {
+            table.push(row);
+            continue;
+        }
+        if !solved {
+            // Benchmarking would fail the same way, without a timeout.
+            row.extend(["-"; 4].map(String::from));
+        } else {
+            match bench.run(solution, &input, &params) {
+                Ok(measurement) => {
+                    let steps = [measurement.parse, measurement.one, measurement.two];
+                    row.extend(steps.iter().map(|stats| match stats {
+                        Some(stats) => format!("{:.2?}", stats.median),
+                        None => String::new(),
+                    }));
+
+                    let total: Duration = steps.iter().flatten().map(|stats| stats.median).sum();
+                    let baseline = *baseline.get_or_insert(total);
+                    row.push(format!(
+                        "{:.2}x",
+                        total.as_secs_f64() / baseline.as_secs_f64().max(f64::EPSILON)
+                    ));
+                }
+                Err(err) => {
+                    eprintln!("Benchmarking {}: {}", variant_name(solution), err);
+                    row.extend(["error"; 4].map(String::from));
+                    failed = true;
+                }
+            }
+        }
+        table.push(row);
+    }
+    print!("{}", table);
+
+    if variants.len() == 1 {
+        eprintln!("{} day {} has no variants", args.year, args.day);
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,94 @@
+use std::{
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use clap::Args;
+
+use super::{parse_seconds, Days};
+use crate::client::{self, Client, Fetched, DEFAULT_BASE_URL};
+
+/// Downloads puzzle inputs into the inputs directory.
+#[derive(Debug, Args)]
+pub struct FetchArgs {
+    year: usize,
+    /// Days such as `5` or `1-10`, or `all`
+    #[clap(default_value = "all")]
+    days: Days,
+    /// Directory caching inputs as `{year}/{day:02}.txt`
+    #[clap(long, default_value = "inputs")]
+    inputs: PathBuf,
+    #[clap(flatten)]
+    server: ServerArgs,
+}
+
+/// How to reach the puzzle server.
+#[derive(Debug, Args)]
+pub struct ServerArgs {
+    /// Session cookie, read from `~/.config/adventofcode/session` if not given
+    #[clap(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    #[clap(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+    /// Minimum number of seconds between two requests
+    #[clap(long, default_value_t = 5.0, value_parser = parse_seconds)]
+    throttle: f64,
+}
+
+impl ServerArgs {
+    pub fn client(self, inputs: &std::path::Path) -> Result<Client, String> {
+        let session = client::session(self.session)?;
+        Ok(Client::new(
+            &self.base_url,
+            &session,
+            inputs,
+            Duration::from_secs_f64(self.throttle),
+        ))
+    }
+}
+
+pub fn fetch(args: FetchArgs) -> ExitCode {
+    let client = match args.server.client(&args.inputs) {
+        Ok(client) => client,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failed = false;
+    for day in args.days.0.clone() {
+        match client.fetch(args.year, day) {
+            Ok(Fetched::Cached(path)) => {
+                eprintln!("{} day {}: cached at {}", args.year, day, path.display())
+            }
+            Ok(Fetched::Downloaded(path)) => {
+                eprintln!(
+                    "{} day {}: downloaded to {}",
+                    args.year,
+                    day,
+                    path.display()
+                )
+            }
+            Ok(Fetched::Locked(unlock)) => {
+                let at = unlock
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs();
+                eprintln!("{} day {}: locked until unix time {}", args.year, day, at);
+                break;
+            }
+            Err(err) => {
+                eprintln!("{} day {}: {}", args.year, day, err);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,198 @@
+use std::{
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use aoc::{Memory, Params, Part, Registry, Run, Solution};
+use clap::Args;
+
+pub mod bench;
+pub mod crosscheck;
+pub mod fetch;
+pub mod new;
+pub mod run;
+pub mod solve;
+pub mod submit;
+pub mod validate;
+pub mod verify;
+
+/// Where the puzzle input of a day is kept inside the inputs directory.
+pub fn input_path(inputs: &Path, year: usize, day: usize) -> PathBuf {
+    inputs.join(year.to_string()).join(format!("{day:02}.txt"))
+}
+
+/// What the main solution of a day is called next to its variants.
+pub const MAIN: &str = "main";
+
+pub fn variant_name(solution: &dyn Solution) -> &'static str {
+    solution.variant().unwrap_or(MAIN)
+}
+
+/// Runs `solution`, giving up after `timeout` seconds if there is one.
+pub fn run_solution(
+    solution: &'static dyn Solution,
+    input: String,
+    parts: &[Part],
+    params: Params,
+    timeout: Option<f64>,
+) -> Run {
+    match timeout {
+        Some(timeout) => aoc::run_with_timeout(
+            solution,
+            input,
+            parts,
+            params,
+            Duration::from_secs_f64(timeout),
+        ),
+        None => solution.run(&input, parts, &params),
+    }
+}
+
+/// Whether the binary counts allocations, see the `alloc-stats` feature.
+pub const ALLOC_STATS: bool = cfg!(feature = "alloc-stats");
+
+/// Allocation count, bytes allocated and peak memory of a part.
+pub fn memory_cells(memory: Option<Memory>) -> [String; 3] {
+    match memory {
+        Some(memory) => [
+            memory.allocations.to_string(),
+            bytes(memory.allocated),
+            bytes(memory.peak),
+        ],
+        None => Default::default(),
+    }
+}
+
+/// Formats a byte count with a binary unit, such as `1.5 KiB`.
+pub fn bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Parses a `--param KEY=VALUE` argument.
+pub fn parse_param(s: &str) -> Result<(String, String), String> {
+    s.split_once('=')
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .ok_or_else(|| format!("expected KEY=VALUE, got {s:?}"))
+}
+
+/// A number of seconds that makes a [`Duration`], so not negative or infinite.
+pub fn parse_seconds(s: &str) -> Result<f64, String> {
+    let secs = s.parse::<f64>().map_err(|err| err.to_string())?;
+    Duration::try_from_secs_f64(secs)
+        .map(|_| secs)
+        .map_err(|err| err.to_string())
+}
+
+/// Which registered solutions a command works on.
+#[derive(Debug, Args)]
+pub struct Selection {
+    /// Year to select, or `all`
+    #[clap(default_value = "all")]
+    year: Years,
+    /// Days such as `5` or `1-10`, or `all`
+    #[clap(default_value = "all")]
+    days: Days,
+}
+
+impl Selection {
+    pub fn solutions(&self, registry: &Registry) -> Vec<&'static dyn Solution> {
+        registry
+            .iter()
+            .filter(|solution| match self.year {
+                Years::All => true,
+                Years::One(year) => solution.year() == year,
+            })
+            .filter(|solution| self.days.0.contains(&solution.day()))
+            .collect()
+    }
+
+    /// The selected solutions, or with a `variant` its namesake of each
+    /// selected day, leaving out days without one.
+    pub fn variants(
+        &self,
+        registry: &Registry,
+        variant: Option<&str>,
+    ) -> Vec<&'static dyn Solution> {
+        let solutions = self.solutions(registry);
+        match variant {
+            None | Some(MAIN) => solutions,
+            Some(name) => solutions
+                .into_iter()
+                .filter_map(|solution| registry.variant(solution.year(), solution.day(), name))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Years {
+    All,
+    One(usize),
+}
+
+impl FromStr for Years {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            year => year
+                .parse()
+                .map(Self::One)
+                .map_err(|_| format!("Unknown year {}", year)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Days(RangeInclusive<usize>);
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let day = |day: &str| {
+            day.trim()
+                .parse::<usize>()
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| format!("Unknown day {}", day))
+        };
+
+        match s.split_once('-') {
+            _ if s == "all" => Ok(Self(1..=25)),
+            Some((first, last)) => {
+                let days = day(first)?..=day(last)?;
+                if days.is_empty() {
+                    return Err(format!("Days {} are in reverse", s));
+                }
+                Ok(Self(days))
+            }
+            None => day(s).map(|day| Self(day..=day)),
+        }
+    }
+}
+
+#[test]
+fn parse_days() {
+    assert_eq!("all".parse::<Days>().unwrap().0, 1..=25);
+    assert_eq!("7".parse::<Days>().unwrap().0, 7..=7);
+    assert_eq!("3-12".parse::<Days>().unwrap().0, 3..=12);
+    assert!("x-2".parse::<Days>().is_err());
+    assert!("0".parse::<Days>().is_err());
+    assert!("26".parse::<Days>().is_err());
+    assert!("20-30".parse::<Days>().is_err());
+    assert!("10-3".parse::<Days>().is_err());
+}
@@ -0,0 +1,102 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use clap::Args;
+
+/// Creates a solution module for a new day from a template. The build
+/// script picks it up from there.
+#[derive(Debug, Args)]
+pub struct NewArgs {
+    #[clap(short, long)]
+    year: usize,
+    #[clap(short, long)]
+    day: usize,
+    /// Source directory of the solutions crate
+    #[clap(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/src"))]
+    src: PathBuf,
+}
+
+const TEMPLATE: &str = r#"use aoc::*;
+
+#[derive(Debug, Date)]
+#[date(year = {year}, day = {day})]
+pub struct Day{dd};
+
+impl Solution for Day{dd} {
+    fn part_one(&self, _input: &str) -> AocResult {
+        Err(Error::NotImplemented)
+    }
+
+    fn part_two(&self, _input: &str) -> AocResult {
+        Err(Error::NotImplemented)
+    }
+}
+
+#[test]
+#[ignore = "needs the example from the puzzle"]
+fn test() {
+    let input = "";
+    assert_solution!(Day{dd}.part_one, input, "");
+    assert_solution!(Day{dd}.part_two, input, "");
+}
+"#;
+
+pub fn new(args: NewArgs) -> ExitCode {
+    match create(&args) {
+        Ok(path) => {
+            eprintln!("Created {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn create(args: &NewArgs) -> Result<PathBuf, String> {
+    let (year, day) = (args.year, args.day);
+    if year < 2015 || !(1..=25).contains(&day) {
+        return Err(format!("There is no puzzle for {} day {}", year, day));
+    }
+
+    let dir = args.src.join(format!("year_{year}"));
+    let path = dir.join(format!("day{day:02}.rs"));
+    if path.exists() {
+        return Err(format!("{} already exists", path.display()));
+    }
+
+    let solution = TEMPLATE
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{dd}", &format!("{day:02}"));
+
+    std::fs::create_dir_all(&dir).map_err(|err| err.to_string())?;
+    std::fs::write(&path, solution).map_err(|err| err.to_string())?;
+
+    Ok(path)
+}
+
+#[test]
+fn creates_days() {
+    let src = crate::client::tests::temp_dir("new");
+    let args = NewArgs {
+        year: 2024,
+        day: 3,
+        src: src.clone(),
+    };
+
+    let path = create(&args).unwrap();
+    assert_eq!(path, src.join("year_2024").join("day03.rs"));
+    let solution = std::fs::read_to_string(&path).unwrap();
+    assert!(solution.contains("#[date(year = 2024, day = 3)]\npub struct Day03;"));
+    assert!(solution.contains("impl Solution for Day03 {"));
+    assert!(solution.contains("assert_solution!(Day03.part_one"));
+
+    std::fs::write(&path, "// solved").unwrap();
+    assert!(create(&args).unwrap_err().contains("already exists"));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "// solved");
+
+    let args = NewArgs { day: 26, ..args };
+    assert!(create(&args).is_err());
+    std::fs::remove_dir_all(src).ok();
+}
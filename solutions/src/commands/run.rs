@@ -0,0 +1,67 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use aoc::{Params, Part, Registry};
+use clap::Args;
+
+use super::{input_path, parse_seconds, run_solution, Selection};
+use crate::report::{self, Format, Record};
+
+/// Runs both parts of every selected solution on its input.
+#[derive(Debug, Args)]
+pub struct RunArgs {
+    #[clap(flatten)]
+    selection: Selection,
+    /// Run this alternative implementation of each day, skipping days
+    /// without it
+    #[clap(long)]
+    variant: Option<String>,
+    /// Directory containing inputs as `{year}/{day:02}.txt`
+    #[clap(long, default_value = "inputs")]
+    inputs: PathBuf,
+    /// Give up on a solution after this many seconds
+    #[clap(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<f64>,
+    /// Print grid answers as drawn instead of the letters they spell
+    #[clap(long)]
+    raw: bool,
+    /// How to print the results
+    #[clap(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+pub fn run(args: RunArgs, registry: &Registry) -> ExitCode {
+    let mut records = vec![];
+
+    for solution in args.selection.variants(registry, args.variant.as_deref()) {
+        let (year, day) = (solution.year(), solution.day());
+
+        let Ok(input) = std::fs::read_to_string(input_path(&args.inputs, year, day)) else {
+            for part in [Part::One, Part::Two] {
+                records.push(Record::missing_input(year, day, part));
+            }
+            continue;
+        };
+
+        let run = run_solution(
+            solution,
+            input,
+            &[Part::One, Part::Two],
+            Params::new(),
+            args.timeout,
+        );
+        for part in run.parts {
+            records.push(Record::new(year, day, run.parse, part, args.raw));
+        }
+    }
+
+    match report::render(&records, args.format) {
+        Some(report) => print!("{}", report),
+        None => print!("{}", report::table(&records)),
+    }
+
+    if records.iter().any(|record| record.status.is_failure()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,212 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc::{Context, Params, Part, PartRun, Registry};
+use clap::Args;
+
+use super::{bytes, parse_param, parse_seconds, run_solution, variant_name, MAIN};
+use crate::{
+    examples::{self, Example},
+    report::{self, Format, Record},
+    visualize::VisualizeArgs,
+};
+
+/// Solves one day for a single input.
+#[derive(Debug, Args)]
+pub struct SolveArgs {
+    #[clap(short, long)]
+    year: usize,
+    #[clap(short, long)]
+    day: usize,
+    /// Part to solve, both parts if omitted
+    #[clap(short, long)]
+    part: Option<Part>,
+    /// Alternative implementation of the day to use instead of the main one
+    #[clap(long)]
+    variant: Option<String>,
+    /// Puzzle parameter overriding the default for real inputs, may be repeated
+    #[clap(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+    /// Give up on a solution after this many seconds
+    #[clap(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<f64>,
+    /// Print how long each part took to stderr, and how much it allocated
+    /// when built with the `alloc-stats` feature
+    #[clap(short, long)]
+    stats: bool,
+    /// Print grid answers as drawn instead of the letters they spell
+    #[clap(long)]
+    raw: bool,
+    /// How to print the results
+    #[clap(long, value_enum, default_value_t)]
+    format: Format,
+    #[clap(flatten)]
+    visualize: VisualizeArgs,
+    /// Solve the Nth example of the day instead of an input file, checking
+    /// the answers against the expected ones
+    #[clap(long, value_name = "N", conflicts_with = "input")]
+    example: Option<usize>,
+    /// Directory containing examples as `{year}/{day:02}/{n}.txt`
+    #[clap(long, value_name = "DIR", default_value = examples::DEFAULT_DIR)]
+    examples: PathBuf,
+    /// Input file, `-` for stdin
+    #[clap(required_unless_present = "example")]
+    input: Option<PathBuf>,
+}
+
+pub fn solve(args: SolveArgs, registry: &Registry) -> ExitCode {
+    let Some(solution) = registry.get(args.year, args.day) else {
+        let days: Vec<_> = registry
+            .year(args.year)
+            .map(|solution| solution.day().to_string())
+            .collect();
+        eprintln!("No solution for {} day {}", args.year, args.day);
+        if !days.is_empty() {
+            eprintln!("Solved days of {}: {}", args.year, days.join(", "));
+        }
+        return ExitCode::FAILURE;
+    };
+    let solution = match &args.variant {
+        Some(name) => match registry.variant(args.year, args.day, name) {
+            Some(variant) => variant,
+            None if name == MAIN => solution,
+            None => {
+                let variants: Vec<_> = registry
+                    .variants(args.year, args.day)
+                    .map(variant_name)
+                    .collect();
+                eprintln!("No variant {} of {} day {}", name, args.year, args.day);
+                eprintln!("Variants: {}", variants.join(", "));
+                return ExitCode::FAILURE;
+            }
+        },
+        None => solution,
+    };
+
+    let example = match args.example {
+        Some(n) => match Example::load(&args.examples, args.year, args.day, n) {
+            Ok(example) => Some(example),
+            Err(err) => {
+                eprintln!("{}", err);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let input = match (&example, &args.input) {
+        (Some(example), _) => Ok(example.input.clone()),
+        (None, Some(path)) => read_input(path),
+        (None, None) => unreachable!("clap requires an input without an example"),
+    };
+    let input = match input {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+    // Parameters given on the command line override those of the example.
+    let params: Params = example
+        .iter()
+        .flat_map(Example::params)
+        .chain(args.params)
+        .collect();
+    let run = match args.visualize.start() {
+        Some((frames, viewer)) => {
+            let run = Context::current()
+                .with_frames(frames)
+                .enter(|| run_solution(solution, input, &parts, params, args.timeout));
+            match viewer.finish() {
+                Ok(0) => eprintln!("{} {} has nothing to visualize", args.year, args.day),
+                Ok(_) => (),
+                Err(err) => eprintln!("Could not show frames: {}", err),
+            }
+            run
+        }
+        None => run_solution(solution, input, &parts, params, args.timeout),
+    };
+
+    // Checked before printing, so that a wrong answer fails in every format.
+    let mut failed = false;
+    for part in &run.parts {
+        if let (Some(example), Ok(answer)) = (&example, &part.result) {
+            if let Some(mismatch) = example.mismatch(part.part, answer) {
+                eprintln!("Part {:?} {}", part.part, mismatch);
+                failed = true;
+            }
+        }
+    }
+
+    if args.format != Format::Text {
+        let records: Vec<_> = run
+            .parts
+            .into_iter()
+            .map(|part| Record::new(args.year, args.day, run.parse, part, args.raw))
+            .collect();
+        print!(
+            "{}",
+            report::render(&records, args.format).unwrap_or_default()
+        );
+        return if failed || records.iter().any(|record| record.status.is_failure()) {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
+        };
+    }
+
+    for PartRun {
+        part,
+        result,
+        time,
+        memory,
+    } in run.parts
+    {
+        if args.stats {
+            match memory {
+                Some(memory) => eprintln!(
+                    "Part {:?}: {:.2?}, {} allocations, {} allocated, {} peak",
+                    part,
+                    time,
+                    memory.allocations,
+                    bytes(memory.allocated),
+                    bytes(memory.peak)
+                ),
+                None => eprintln!("Part {:?}: {:.2?}", part, time),
+            }
+        }
+        match result {
+            Ok(output) => println!("{}", report::answer_text(&output, args.raw)),
+            Err(err) => {
+                eprintln!("{}", err);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Reads an input file, or stdin for `-`.
+fn read_input(path: &Path) -> Result<String, String> {
+    let mut input = String::new();
+    let read = if path.as_os_str() == "-" {
+        std::io::stdin().read_to_string(&mut input).map(drop)
+    } else {
+        std::fs::read_to_string(path).map(|read| input = read)
+    };
+    read.map(|_| input)
+        .map_err(|err| format!("Could not read {}: {}", path.display(), err))
+}
@@ -0,0 +1,127 @@
+use std::{
+    path::PathBuf,
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc::{Answer, Params, Part, Registry};
+use clap::Args;
+
+use super::{fetch::ServerArgs, input_path};
+use crate::{
+    answers::{part_number, Answers, Known, Submission, SubmissionLog},
+    client::Verdict,
+};
+
+/// Solves a part on its input and submits the answer.
+#[derive(Debug, Args)]
+pub struct SubmitArgs {
+    #[clap(short, long)]
+    year: usize,
+    #[clap(short, long)]
+    day: usize,
+    #[clap(short, long)]
+    part: Part,
+    /// Directory containing inputs as `{year}/{day:02}.txt`
+    #[clap(long, default_value = "inputs")]
+    inputs: PathBuf,
+    /// Directory containing known answers as `{year}.json`
+    #[clap(long, default_value = "answers")]
+    answers: PathBuf,
+    /// Store an accepted answer as a salted hash
+    #[clap(long)]
+    hash: bool,
+    #[clap(flatten)]
+    server: ServerArgs,
+}
+
+pub fn submit(args: SubmitArgs, registry: &Registry) -> ExitCode {
+    match try_submit(args, registry) {
+        Ok(Verdict::Correct | Verdict::AlreadySolved) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn try_submit(args: SubmitArgs, registry: &Registry) -> Result<Verdict, String> {
+    let (year, day, part) = (args.year, args.day, args.part);
+    let solution = registry
+        .get(year, day)
+        .ok_or_else(|| format!("No solution for {} day {}", year, day))?;
+
+    let path = input_path(&args.inputs, year, day);
+    let input = std::fs::read_to_string(&path)
+        .map_err(|err| format!("Could not read {}: {}", path.display(), err))?;
+    // Run rather than solved directly, so that a panic is reported as such.
+    let run = solution.run(&input, &[part], &Params::new());
+    let answer = run
+        .parts
+        .into_iter()
+        .next()
+        .expect("one part was run")
+        .result
+        .map_err(|err| err.to_string())?;
+    let submitted = submitted(&answer)?;
+    println!("{}", submitted);
+
+    let mut answers = Answers::load(&args.answers, year).map_err(|err| err.to_string())?;
+    if let Some(known) = answers.get(day, part) {
+        return if known.matches(&answer) {
+            eprintln!("Already known to be correct");
+            Ok(Verdict::Correct)
+        } else {
+            Err("A different answer is already known to be correct".into())
+        };
+    }
+
+    let mut log = SubmissionLog::load(&args.inputs, year).map_err(|err| err.to_string())?;
+    if let Some(reason) = log.refusal(day, part, &submitted) {
+        return Err(format!("Not submitting, {}", reason));
+    }
+
+    let client = args.server.client(&args.inputs)?;
+    let verdict = client.submit(year, day, part, &submitted)?;
+    eprintln!("{}", verdict);
+
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    log.append(Submission {
+        day,
+        part: part_number(part),
+        answer: submitted,
+        verdict: verdict.clone(),
+        time,
+    })
+    .map_err(|err| err.to_string())?;
+
+    if verdict == Verdict::Correct {
+        let known = if args.hash {
+            Known::hashed(&answer)
+        } else {
+            Known::Plain(answer)
+        };
+        answers.insert(day, part, known);
+        answers.save().map_err(|err| err.to_string())?;
+    }
+
+    Ok(verdict)
+}
+
+/// The answer as typed into the puzzle page, which takes the letters drawn by
+/// grid answers. Known answers keep the grid, as solved.
+fn submitted(answer: &Answer) -> Result<Answer, String> {
+    match answer {
+        Answer::Grid(rows) => aoc::ocr(rows).map(Answer::String).map_err(|err| {
+            format!(
+                "Could not read letters off the answer, {}:\n{}",
+                err, answer
+            )
+        }),
+        _ => Ok(answer.clone()),
+    }
+}
@@ -0,0 +1,268 @@
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc::{Answer, Error, Part, Registry};
+use clap::Args;
+
+use super::{parse_param, parse_seconds, run_solution, variant_name, Selection};
+use crate::{examples::Example, report, table::Table};
+
+/// Runs the selected solutions on every input in a day's directory, such as
+/// the inputs of teammates, to find solutions that only work for one input.
+#[derive(Debug, Args)]
+pub struct ValidateArgs {
+    #[clap(flatten)]
+    selection: Selection,
+    /// Directory containing more inputs as `{year}/{day:02}/{name}.txt`, each
+    /// with optional answers and parameters in `{name}.json`
+    #[clap(long, default_value = "inputs")]
+    inputs: PathBuf,
+    /// Give up on a solution after this many seconds
+    #[clap(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<f64>,
+    /// Puzzle parameter overriding those of the inputs, may be repeated
+    #[clap(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+}
+
+pub fn validate(args: ValidateArgs, registry: &Registry) -> ExitCode {
+    let mut table = Table::new([
+        "Year", "Day", "Variant", "Input", "Part", "Answer", "Expected", "Status",
+    ]);
+    let mut runs = 0;
+    let mut failures = 0;
+    for solution in args.selection.solutions(registry) {
+        let (year, day) = (solution.year(), solution.day());
+        let dir = args.inputs.join(year.to_string()).join(format!("{day:02}"));
+        let paths = match input_files(&dir) {
+            Ok(paths) => paths,
+            Err(err) => {
+                // An unreadable file or directory fails without stopping the
+                // others.
+                let err = format!("Could not list {}: {}", dir.display(), err);
+                table.push(invalid(year, day, "", &err));
+                runs += 1;
+                failures += 1;
+                continue;
+            }
+        };
+
+        for path in paths {
+            let name = path.file_stem().unwrap_or_default().to_string_lossy();
+            let example = match Example::read(&path) {
+                Ok(example) => example,
+                Err(err) => {
+                    table.push(invalid(year, day, &name, &err));
+                    runs += 1;
+                    failures += 1;
+                    continue;
+                }
+            };
+
+            for variant in registry.variants(year, day) {
+                runs += 1;
+                let params = example.params().chain(args.params.clone()).collect();
+                let run = run_solution(
+                    variant,
+                    example.input.clone(),
+                    &[Part::One, Part::Two],
+                    params,
+                    args.timeout,
+                );
+
+                let mut failed = false;
+                for part in run.parts {
+                    let (answer, status) = check(&example, part.part, part.result);
+                    failed |= status.is_failure();
+                    table.push([
+                        year.to_string(),
+                        day.to_string(),
+                        variant_name(variant).to_string(),
+                        name.to_string(),
+                        format!("{:?}", part.part),
+                        report::one_line(&answer),
+                        report::one_line(&example.expected(part.part).unwrap_or_default()),
+                        status.as_str().to_string(),
+                    ]);
+                }
+                failures += failed as usize;
+            }
+        }
+    }
+
+    if runs == 0 {
+        eprintln!(
+            "No inputs found as {}/{{year}}/{{day:02}}/*.txt",
+            args.inputs.display()
+        );
+        return ExitCode::FAILURE;
+    }
+
+    print!("{}", table);
+    if failures > 0 {
+        eprintln!("{} of {} runs failed", failures, runs);
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// How a part did on one of the inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Ok,
+    /// Solved, but the sidecar has no answer to compare with.
+    Unchecked,
+    Wrong,
+    NotImplemented,
+    TimedOut,
+    Panicked,
+    Error,
+    /// The input, its sidecar or the directory couldn't be read.
+    Invalid,
+}
+
+impl Status {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Unchecked => "unchecked",
+            Self::Wrong => "wrong",
+            Self::NotImplemented => "not implemented",
+            Self::TimedOut => "timed out",
+            Self::Panicked => "panicked",
+            Self::Error => "error",
+            Self::Invalid => "invalid",
+        }
+    }
+
+    fn is_failure(&self) -> bool {
+        !matches!(self, Self::Ok | Self::Unchecked | Self::NotImplemented)
+    }
+}
+
+/// A row for an input that couldn't be read, or a directory that couldn't be
+/// listed.
+fn invalid(year: usize, day: usize, name: &str, err: &str) -> [String; 8] {
+    [
+        year.to_string(),
+        day.to_string(),
+        String::new(),
+        name.to_string(),
+        String::new(),
+        report::one_line(err),
+        String::new(),
+        Status::Invalid.as_str().to_string(),
+    ]
+}
+
+/// The answer as printed, or the error, and how it compares with the one
+/// expected for `example`.
+fn check(example: &Example, part: Part, result: Result<Answer, Error>) -> (String, Status) {
+    match result {
+        Ok(answer) => {
+            let status = match example.mismatch(part, &answer) {
+                Some(_) => Status::Wrong,
+                None if example.expected(part).is_none() => Status::Unchecked,
+                None => Status::Ok,
+            };
+            (answer.to_string(), status)
+        }
+        Err(Error::NotImplemented) => (String::new(), Status::NotImplemented),
+        Err(err) => {
+            let status = match err {
+                Error::Timeout => Status::TimedOut,
+                Error::Panicked { .. } => Status::Panicked,
+                _ => Status::Error,
+            };
+            (err.to_string(), status)
+        }
+    }
+}
+
+/// The `.txt` files of a day's directory by name, none if it doesn't exist.
+fn input_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err),
+    };
+    let mut paths = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::temp_dir;
+
+    #[test]
+    fn lists_inputs() {
+        let dir = temp_dir("validate-list");
+        assert!(input_files(&dir).unwrap().is_empty());
+
+        std::fs::create_dir_all(&dir).unwrap();
+        for file in ["b.txt", "a.txt", "a.json", "notes.md"] {
+            std::fs::write(dir.join(file), "").unwrap();
+        }
+        assert_eq!(
+            input_files(&dir).unwrap(),
+            [dir.join("a.txt"), dir.join("b.txt")]
+        );
+        assert!(input_files(&dir.join("a.txt")).is_err());
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn checks_answers() {
+        let dir = temp_dir("validate-check");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.txt"), "input").unwrap();
+        std::fs::write(
+            dir.join("a.json"),
+            r#"{"part1": "7", "params": {"n": "2"}}"#,
+        )
+        .unwrap();
+        let example = Example::read(&dir.join("a.txt")).unwrap();
+        assert_eq!(example.input, "input");
+        assert_eq!(
+            example.params().collect::<Vec<_>>(),
+            [("n".into(), "2".into())]
+        );
+
+        let status = |part, result| check(&example, part, result).1;
+        assert_eq!(status(Part::One, Ok(7u8.into())), Status::Ok);
+        assert_eq!(status(Part::One, Ok("7".into())), Status::Ok);
+        assert_eq!(status(Part::One, Ok(8u8.into())), Status::Wrong);
+        assert_eq!(status(Part::Two, Ok(8u8.into())), Status::Unchecked);
+        assert_eq!(
+            status(Part::Two, Err(Error::NotImplemented)),
+            Status::NotImplemented
+        );
+        assert_eq!(status(Part::One, Err(Error::Timeout)), Status::TimedOut);
+        assert!(status(Part::One, Err(Error::Timeout)).is_failure());
+        assert!(!status(Part::Two, Ok(8u8.into())).is_failure());
+
+        std::fs::write(dir.join("b.txt"), "input").unwrap();
+        assert!(Example::read(&dir.join("b.txt"))
+            .unwrap()
+            .expected(Part::One)
+            .is_none());
+        for sidecar in ["{", r#"{"part3": "1"}"#] {
+            std::fs::write(dir.join("b.json"), sidecar).unwrap();
+            let err = Example::read(&dir.join("b.txt")).unwrap_err();
+            assert!(err.starts_with("Invalid"), "{err}");
+        }
+        assert!(Example::read(&dir.join("c.txt")).is_err());
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
@@ -0,0 +1,152 @@
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use aoc::{Error, Params, Part, Registry};
+use clap::Args;
+
+use super::{input_path, parse_seconds, run_solution, Selection};
+use crate::{
+    answers::{Answers, Known},
+    table::Table,
+};
+
+/// Runs the selected solutions and compares them with the known answers.
+#[derive(Debug, Args)]
+pub struct VerifyArgs {
+    #[clap(flatten)]
+    selection: Selection,
+    /// Directory containing inputs as `{year}/{day:02}.txt`
+    #[clap(long, default_value = "inputs")]
+    inputs: PathBuf,
+    /// Give up on a solution after this many seconds
+    #[clap(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<f64>,
+    /// Directory containing known answers as `{year}.json`
+    #[clap(long, default_value = "answers")]
+    answers: PathBuf,
+    /// Store answers that aren't known yet
+    #[clap(long)]
+    record: bool,
+    /// Store recorded answers as salted hashes
+    #[clap(long, requires = "record")]
+    hash: bool,
+}
+
+pub fn verify(args: VerifyArgs, registry: &Registry) -> ExitCode {
+    let mut table = Table::new(["Year", "Day", "Part", "Answer", "Expected", "Status"]);
+    let mut answers: BTreeMap<usize, Answers> = BTreeMap::new();
+    let mut failed = false;
+
+    for solution in args.selection.solutions(registry) {
+        let (year, day) = (solution.year(), solution.day());
+
+        let answers = match answers.entry(year) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => match Answers::load(&args.answers, year) {
+                Ok(loaded) => entry.insert(loaded),
+                Err(err) => {
+                    eprintln!("Could not load answers of {}: {}", year, err);
+                    return ExitCode::FAILURE;
+                }
+            },
+        };
+
+        let Ok(input) = std::fs::read_to_string(input_path(&args.inputs, year, day)) else {
+            for part in [Part::One, Part::Two] {
+                let expected = expected(answers.get(day, part));
+                table.push(row(year, day, part, "", &expected, "missing input"));
+            }
+            continue;
+        };
+
+        let run = run_solution(
+            solution,
+            input,
+            &[Part::One, Part::Two],
+            Params::new(),
+            args.timeout,
+        );
+        for part in run.parts {
+            let known = answers.get(day, part.part).cloned();
+            let expected = expected(known.as_ref());
+
+            let (answer, status) = match (part.result, known) {
+                (Err(Error::NotImplemented), _) => (String::new(), "not implemented"),
+                (Err(err @ Error::Timeout), _) => {
+                    failed = true;
+                    (err.to_string(), "timed out")
+                }
+                (Err(err @ Error::Panicked { .. }), _) => {
+                    failed = true;
+                    (err.to_string(), "panicked")
+                }
+                (Err(err), _) => {
+                    failed = true;
+                    (err.to_string(), "error")
+                }
+                (Ok(answer), Some(known)) if known.matches(&answer) => (answer.to_string(), "ok"),
+                (Ok(answer), Some(_)) => {
+                    failed = true;
+                    (answer.to_string(), "regression")
+                }
+                (Ok(answer), None) if args.record => {
+                    let known = if args.hash {
+                        Known::hashed(&answer)
+                    } else {
+                        Known::Plain(answer.clone())
+                    };
+                    answers.insert(day, part.part, known);
+                    (answer.to_string(), "recorded")
+                }
+                (Ok(answer), None) => (answer.to_string(), "unknown"),
+            };
+            table.push(row(year, day, part.part, &answer, &expected, status));
+        }
+    }
+
+    print!("{}", table);
+
+    if args.record {
+        for (year, answers) in answers.iter() {
+            if let Err(err) = answers.save() {
+                eprintln!("Could not save answers of {}: {}", year, err);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn expected(known: Option<&Known>) -> String {
+    match known {
+        Some(Known::Plain(answer)) => answer.to_string(),
+        Some(Known::Hashed { .. }) => "(hashed)".to_string(),
+        None => String::new(),
+    }
+}
+
+fn row(
+    year: usize,
+    day: usize,
+    part: Part,
+    answer: &str,
+    expected: &str,
+    status: &str,
+) -> [String; 6] {
+    [
+        year.to_string(),
+        day.to_string(),
+        format!("{:?}", part),
+        answer.lines().collect::<Vec<_>>().join(" / "),
+        expected.lines().collect::<Vec<_>>().join(" / "),
+        status.to_string(),
+    ]
+}
@@ -0,0 +1,147 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use aoc::{Answer, Part};
+use serde::Deserialize;
+
+/// Where examples are kept unless told otherwise.
+pub const DEFAULT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+/// An example from a puzzle text, kept as `{examples}/{year}/{day:02}/{n}.txt`
+/// next to a `{n}.json` with its answers and parameters, such as
+/// `{"part1": "374", "part2": "1030", "params": {"expansion": "10"}}`.
+///
+/// The sidecar is the one place the answers of an example are kept, so they
+/// aren't repeated in `#[example]` attributes. It is what `solve --example`
+/// reads and what every variant of the day is checked against.
+#[derive(Debug)]
+pub struct Example {
+    pub input: String,
+    sidecar: Sidecar,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Sidecar {
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+    #[serde(default)]
+    params: BTreeMap<String, String>,
+}
+
+/// An answer as written in a sidecar, grids either as one string or a list
+/// of rows.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Text(String),
+    Rows(Vec<String>),
+}
+
+impl Expected {
+    fn text(&self) -> String {
+        match self {
+            Self::Text(text) => text.clone(),
+            Self::Rows(rows) => rows.join("\n"),
+        }
+    }
+}
+
+/// Where the `n`th example of a day is kept inside the examples directory.
+pub fn example_path(examples: &Path, year: usize, day: usize, n: usize) -> PathBuf {
+    examples
+        .join(year.to_string())
+        .join(format!("{day:02}"))
+        .join(format!("{n}.txt"))
+}
+
+impl Example {
+    pub fn load(examples: &Path, year: usize, day: usize, n: usize) -> Result<Self, String> {
+        Self::read(&example_path(examples, year, day, n))
+    }
+
+    /// Reads any input file with the answers and parameters of its sidecar,
+    /// which is optional.
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let input = std::fs::read_to_string(path)
+            .map_err(|err| format!("Could not read {}: {}", path.display(), err))?;
+
+        let path = path.with_extension("json");
+        let sidecar = match std::fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|err| format!("Invalid {}: {}", path.display(), err))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Sidecar::default(),
+            Err(err) => return Err(format!("Could not read {}: {}", path.display(), err)),
+        };
+        Ok(Self { input, sidecar })
+    }
+
+    pub fn params(&self) -> impl Iterator<Item = (String, String)> + '_ {
+        self.sidecar
+            .params
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+    }
+
+    /// The expected answer of `part`, if the sidecar has one.
+    pub fn expected(&self, part: Part) -> Option<String> {
+        match part {
+            Part::One => self.sidecar.part1.as_ref(),
+            Part::Two => self.sidecar.part2.as_ref(),
+        }
+        .map(Expected::text)
+    }
+
+    /// A description of how `answer` differs from the expected one, if it
+    /// does.
+    pub fn mismatch(&self, part: Part, answer: &Answer) -> Option<String> {
+        let expected = self.expected(part)?;
+        let answer = answer.to_string();
+        (answer != expected).then(|| aoc::diff(&expected, &answer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{Params, Registry};
+
+    use super::*;
+
+    /// Run by the tests generated for every example file, see build.rs, and
+    /// checks every variant of the day.
+    fn check(year: usize, day: usize, n: usize) {
+        let registry = Registry::new();
+        let variants: Vec<_> = registry.variants(year, day).collect();
+        assert!(!variants.is_empty(), "no solution for {year} day {day}");
+        let example = Example::load(Path::new(DEFAULT_DIR), year, day, n).unwrap();
+        let params: Params = example.params().collect();
+
+        let mut checked = 0;
+        let mut failures = vec![];
+        for part in [Part::One, Part::Two] {
+            let Some(expected) = example.expected(part) else {
+                continue;
+            };
+            checked += 1;
+            for &solution in &variants {
+                if let Err(message) =
+                    aoc::check_example(solution, &example.input, part, &params, &expected)
+                {
+                    failures.push(format!("{solution:?} part {part:?}: {message}"));
+                }
+            }
+        }
+        let path = example_path(Path::new(DEFAULT_DIR), year, day, n);
+        assert!(
+            checked > 0,
+            "{} has no answers",
+            path.with_extension("json").display()
+        );
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    // `mod year_YYYY { mod dayDD { #[test] fn example_N() } }`, see build.rs.
+    include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+}
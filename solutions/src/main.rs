@@ -1,56 +1,125 @@
-#![feature(int_roundings)]
-
-mod year_2021;
-mod year_2022;
-mod year_2023;
+// `mod year_YYYY { pub mod dayDD; }` for every day, see build.rs.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
 
+mod answers;
+mod client;
+mod commands;
+mod examples;
+mod profile;
+mod report;
+mod table;
 mod util;
+mod visualize;
+
+use std::{io::IsTerminal, process::ExitCode};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: aoc::CountingAllocator = aoc::CountingAllocator;
 
 use aoc::*;
-use clap::Parser;
-use std::{collections::HashMap, io::Read, path::PathBuf, str::FromStr, time::Instant};
+use clap::{ArgAction, Parser, Subcommand};
+use commands::{
+    bench::BenchArgs, crosscheck::CrosscheckArgs, fetch::FetchArgs, new::NewArgs, run::RunArgs,
+    solve::SolveArgs, submit::SubmitArgs, validate::ValidateArgs, verify::VerifyArgs,
+};
+use profile::Profile;
+use tracing_subscriber::{
+    filter::LevelFilter, layer::SubscriberExt, util::SubscriberInitExt, Layer,
+};
 
 #[derive(Debug, Parser)]
-#[clap(author = "Luka Dornhecker")]
+#[clap(author = "Luka Dornhecker", args_conflicts_with_subcommands = true)]
 struct Opts {
-    #[clap(short, long)]
-    year: usize,
-    #[clap(short, long)]
-    day: usize,
-    #[clap(short, long)]
-    part: Part,
-    #[clap(short, long)]
-    bench: bool,
-    input: PathBuf,
+    #[clap(subcommand)]
+    command: Option<Command>,
+    #[clap(flatten)]
+    solve: Option<SolveArgs>,
+    /// Log what solutions are doing to stderr, `-vv` for every step
+    #[clap(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Print a tree of the time spent in each traced phase to stderr
+    #[clap(long, global = true)]
+    profile: bool,
 }
 
-type Date = (usize, usize);
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one day for a single input
+    Solve(SolveArgs),
+    /// Run every selected solution on its input and print a table of results
+    Run(RunArgs),
+    /// Benchmark parsing and solving of every selected solution
+    Bench(BenchArgs),
+    /// Check that every variant of a day gives the same answers and compare
+    /// their speed
+    Crosscheck(CrosscheckArgs),
+    /// Download puzzle inputs that aren't cached yet
+    Fetch(FetchArgs),
+    /// Create the module of a new day from a template
+    New(NewArgs),
+    /// Solve a part and submit the answer
+    Submit(SubmitArgs),
+    /// Compare the answers of every selected solution with the known ones
+    Verify(VerifyArgs),
+    /// Run every selected solution on each input kept for its day, such as
+    /// those of teammates, and report the inputs it fails on
+    Validate(ValidateArgs),
+}
 
-fn main() {
+fn main() -> ExitCode {
     let opts = Opts::parse();
 
-    let mut solutions: HashMap<Date, _> = HashMap::new();
+    let level = match opts.verbose {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+    let profile = opts.profile.then(Profile::default);
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(std::io::stderr)
+                .with_ansi(std::io::stderr().is_terminal())
+                .with_filter(level),
+        )
+        .with(profile.clone())
+        .init();
 
-    for solution in inventory::iter::<&dyn Solution> {
-        solutions.insert((solution.year(), solution.day()), solution);
+    let registry = Registry::new();
+    for problem in registry.problems() {
+        eprintln!("warning: {}", problem);
     }
 
-    let solution = solutions.get(&(opts.year, opts.day)).unwrap();
+    let code = match (opts.command, opts.solve) {
+        (Some(Command::Solve(args)), _) | (None, Some(args)) => {
+            commands::solve::solve(args, &registry)
+        }
+        (Some(Command::Run(args)), _) => commands::run::run(args, &registry),
+        (Some(Command::Bench(args)), _) => commands::bench::bench(args, &registry),
+        (Some(Command::Crosscheck(args)), _) => commands::crosscheck::crosscheck(args, &registry),
+        (Some(Command::Fetch(args)), _) => commands::fetch::fetch(args),
+        (Some(Command::New(args)), _) => commands::new::new(args),
+        (Some(Command::Submit(args)), _) => commands::submit::submit(args, &registry),
+        (Some(Command::Verify(args)), _) => commands::verify::verify(args, &registry),
+        (Some(Command::Validate(args)), _) => commands::validate::validate(args, &registry),
+        (None, None) => {
+            use clap::CommandFactory;
+            Opts::command().print_help().ok();
+            ExitCode::FAILURE
+        }
+    };
 
-    let mut input = String::new();
-    if opts.input == PathBuf::from_str("-").unwrap() {
-        std::io::stdin().read_to_string(&mut input).unwrap();
-    } else {
-        input = std::fs::read_to_string(opts.input).unwrap();
+    if let Some(profile) = profile {
+        eprint!("{}", profile);
     }
+    code
+}
 
-    let start = Instant::now();
-    let output = solution.solve(&input, opts.part).unwrap();
-    let took = Instant::now().duration_since(start);
-
-    println!("{}", output);
-
-    if opts.bench {
-        eprintln!("Took {:?}", took);
+#[test]
+fn registry_is_consistent() {
+    if let Err(problems) = Registry::new().check() {
+        let problems: Vec<_> = problems.iter().map(ToString::to_string).collect();
+        panic!("inconsistent solution registry:\n{}", problems.join("\n"));
     }
 }
@@ -0,0 +1,167 @@
+use std::{
+    fmt::{self, Write},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use aoc::tracing::{
+    field::{Field, Visit},
+    span, Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+/// Collects how long spans were open into a tree following their nesting,
+/// such as `run` → `solve` → `parse`, `precompute` and `search`.
+#[derive(Debug, Clone, Default)]
+pub struct Profile {
+    root: Arc<Mutex<Node>>,
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    label: String,
+    calls: usize,
+    time: Duration,
+    /// In order of the first call.
+    children: Vec<Node>,
+}
+
+/// Kept in the extensions of a span while it is open.
+struct Timing {
+    label: String,
+    start: Instant,
+}
+
+impl Node {
+    fn child(&mut self, label: &str) -> &mut Node {
+        match self.children.iter().position(|child| child.label == label) {
+            Some(i) => &mut self.children[i],
+            None => {
+                self.children.push(Node {
+                    label: label.to_string(),
+                    ..Default::default()
+                });
+                self.children.last_mut().unwrap()
+            }
+        }
+    }
+
+    fn write(&self, out: &mut String, prefix: &str, last: bool, width: usize) {
+        let branch = if last { "└─ " } else { "├─ " };
+        let label = format!("{prefix}{branch}{}", self.label);
+        let pad = width.saturating_sub(label.chars().count());
+        let calls = match self.calls {
+            1 => String::new(),
+            calls => format!("  ({calls} calls)"),
+        };
+        writeln!(out, "{label}{:pad$}  {:>10.2?}{calls}", "", self.time).unwrap();
+
+        let prefix = format!("{prefix}{}", if last { "   " } else { "│  " });
+        self.write_children(out, &prefix, width);
+    }
+
+    fn write_children(&self, out: &mut String, prefix: &str, width: usize) {
+        for (i, child) in self.children.iter().enumerate() {
+            child.write(out, prefix, i + 1 == self.children.len(), width);
+        }
+    }
+
+    fn width(&self, depth: usize) -> usize {
+        let own = 3 * (depth + 1) + self.label.chars().count();
+        self.children
+            .iter()
+            .map(|child| child.width(depth + 1))
+            .fold(own, usize::max)
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let root = self.root.lock().unwrap();
+        let width = root.children.iter().map(|child| child.width(0)).max();
+        let mut out = String::new();
+        root.write_children(&mut out, "", width.unwrap_or(0));
+        f.write_str(&out)
+    }
+}
+
+impl<S> Layer<S> for Profile
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let mut label = Label(attrs.metadata().name().to_string());
+        attrs.record(&mut label);
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(Timing {
+                label: label.0,
+                start: Instant::now(),
+            });
+        }
+    }
+
+    fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(time) = span
+            .extensions()
+            .get::<Timing>()
+            .map(|timing| timing.start.elapsed())
+        else {
+            return;
+        };
+
+        let mut root = self.root.lock().unwrap();
+        let mut node = &mut *root;
+        for span in span.scope().from_root() {
+            if let Some(timing) = span.extensions().get::<Timing>() {
+                node = node.child(&timing.label);
+            }
+        }
+        node.calls += 1;
+        node.time += time;
+    }
+}
+
+/// A span's name followed by its fields, like `run year=2022 day=16`.
+struct Label(String);
+
+impl Visit for Label {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        write!(self.0, " {}={:?}", field.name(), value).unwrap();
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        write!(self.0, " {}={}", field.name(), value).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::tracing::info_span;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::*;
+
+    #[test]
+    fn nests_spans() {
+        let profile = Profile::default();
+        let subscriber = tracing_subscriber::registry().with(profile.clone());
+        aoc::tracing::subscriber::with_default(subscriber, || {
+            let _run = info_span!("run", day = 16).entered();
+            info_span!("parse").in_scope(|| ());
+            for _ in 0..2 {
+                info_span!("search").in_scope(|| ());
+            }
+        });
+
+        let tree = profile.to_string();
+        let lines: Vec<_> = tree.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("└─ run day=16 "));
+        assert!(lines[1].starts_with("   ├─ parse "));
+        assert!(lines[2].starts_with("   └─ search "));
+        assert!(lines[2].ends_with("(2 calls)"));
+    }
+}
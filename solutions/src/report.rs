@@ -0,0 +1,343 @@
+use std::{collections::BTreeMap, fmt::Write, time::Duration};
+
+use aoc::{Answer, Error, Memory, Part, PartRun};
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+
+use crate::{
+    answers::part_number,
+    commands::{memory_cells, ALLOC_STATS},
+    table::Table,
+};
+
+/// How commands print their results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Plain text for people
+    #[default]
+    Text,
+    /// An array with one object per part
+    Json,
+    /// One row per part with a header
+    Csv,
+    /// A table of stars and timings per year, for a README
+    Markdown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    NotImplemented,
+    MissingInput,
+    TimedOut,
+    Panicked,
+    Error,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::NotImplemented => "not implemented",
+            Self::MissingInput => "missing input",
+            Self::TimedOut => "timed out",
+            Self::Panicked => "panicked",
+            Self::Error => "error",
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::TimedOut | Self::Panicked | Self::Error)
+    }
+}
+
+/// The outcome of one part of one day.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub year: usize,
+    pub day: usize,
+    #[serde(serialize_with = "part")]
+    pub part: Part,
+    pub answer: Option<String>,
+    pub status: Status,
+    /// Parsing shared by both parts, if the solution parses separately.
+    #[serde(rename = "parse_ns", serialize_with = "nanos")]
+    pub parse: Option<Duration>,
+    #[serde(rename = "solve_ns", serialize_with = "nanos")]
+    pub time: Option<Duration>,
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Memory>,
+}
+
+impl Record {
+    /// Grid answers are given as the letters they draw unless `raw` is set.
+    pub fn new(year: usize, day: usize, parse: Option<Duration>, run: PartRun, raw: bool) -> Self {
+        let (answer, status, error) = match run.result {
+            Ok(answer) => (Some(answer_text(&answer, raw)), Status::Ok, None),
+            Err(Error::NotImplemented) => (None, Status::NotImplemented, None),
+            Err(err) => {
+                let status = match err {
+                    Error::Timeout => Status::TimedOut,
+                    Error::Panicked { .. } => Status::Panicked,
+                    _ => Status::Error,
+                };
+                (None, status, Some(err.to_string()))
+            }
+        };
+        Self {
+            year,
+            day,
+            part: run.part,
+            answer,
+            status,
+            parse,
+            time: Some(run.time),
+            error,
+            memory: run.memory,
+        }
+    }
+
+    pub fn missing_input(year: usize, day: usize, part: Part) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: None,
+            status: Status::MissingInput,
+            parse: None,
+            time: None,
+            error: None,
+            memory: None,
+        }
+    }
+}
+
+/// `answer` as printed, with grids read as letters where possible unless
+/// `raw` is set.
+pub fn answer_text(answer: &Answer, raw: bool) -> String {
+    match answer.letters() {
+        Some(letters) if !raw => letters,
+        _ => answer.to_string(),
+    }
+}
+
+/// Renders `records` in a machine readable `format`, `None` for
+/// [`Format::Text`] which every command prints its own way.
+pub fn render(records: &[Record], format: Format) -> Option<String> {
+    match format {
+        Format::Text => None,
+        Format::Json => Some(serde_json::to_string_pretty(records).unwrap() + "\n"),
+        Format::Csv => Some(csv(records)),
+        Format::Markdown => Some(markdown(records)),
+    }
+}
+
+/// The table printed by `run`.
+pub fn table(records: &[Record]) -> Table {
+    let mut header = vec!["Year", "Day", "Part", "Answer", "Time"];
+    if ALLOC_STATS {
+        header.extend(["Allocs", "Allocated", "Peak"]);
+    }
+    header.push("Status");
+
+    let mut table = Table::new(header);
+    for record in records {
+        let answer = match (&record.answer, &record.error) {
+            (Some(answer), _) => one_line(answer),
+            (None, Some(error)) => error.clone(),
+            (None, None) => String::new(),
+        };
+        let mut row = vec![
+            record.year.to_string(),
+            record.day.to_string(),
+            format!("{:?}", record.part),
+            answer,
+            record.time.map(duration).unwrap_or_default(),
+        ];
+        if ALLOC_STATS {
+            row.extend(memory_cells(record.memory));
+        }
+        row.push(record.status.as_str().to_string());
+        table.push(row);
+    }
+    table
+}
+
+fn csv(records: &[Record]) -> String {
+    let mut header = vec![
+        "year", "day", "part", "answer", "status", "parse_ns", "solve_ns", "error",
+    ];
+    if ALLOC_STATS {
+        header.extend(["allocations", "allocated", "peak"]);
+    }
+
+    let mut out = header.join(",") + "\n";
+    for record in records {
+        let nanos =
+            |time: Option<Duration>| time.map_or(String::new(), |t| t.as_nanos().to_string());
+        let mut row = vec![
+            record.year.to_string(),
+            record.day.to_string(),
+            part_number(record.part).to_string(),
+            csv_field(record.answer.as_deref().unwrap_or_default()),
+            record.status.as_str().to_string(),
+            nanos(record.parse),
+            nanos(record.time),
+            csv_field(record.error.as_deref().unwrap_or_default()),
+        ];
+        if ALLOC_STATS {
+            row.extend(match record.memory {
+                Some(memory) => {
+                    [memory.allocations, memory.allocated, memory.peak].map(|n| n.to_string())
+                }
+                None => Default::default(),
+            });
+        }
+        out += &(row.join(",") + "\n");
+    }
+    out
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// One table per year with a row per day: its stars and how long each part
+/// took, with parsing counted towards both parts but only once in the total.
+fn markdown(records: &[Record]) -> String {
+    let mut years: BTreeMap<usize, BTreeMap<usize, Vec<&Record>>> = BTreeMap::new();
+    for record in records {
+        years
+            .entry(record.year)
+            .or_default()
+            .entry(record.day)
+            .or_default()
+            .push(record);
+    }
+
+    let mut out = String::new();
+    for (year, days) in years {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        writeln!(out, "## {year}\n").unwrap();
+        writeln!(out, "| Day | Stars | Part One | Part Two |").unwrap();
+        writeln!(out, "| --: | :---- | -------: | -------: |").unwrap();
+
+        let (mut stars, mut total) = (0, Duration::ZERO);
+        for (day, records) in days {
+            let solved: Vec<_> = records
+                .iter()
+                .filter(|record| record.status == Status::Ok)
+                .collect();
+            let time = |part| {
+                let record = solved.iter().find(|record| record.part == part)?;
+                Some(record.parse.unwrap_or_default() + record.time.unwrap_or_default())
+            };
+            let (one, two) = (time(Part::One), time(Part::Two));
+
+            stars += solved.len();
+            total += solved
+                .first()
+                .and_then(|record| record.parse)
+                .unwrap_or_default();
+            total += solved
+                .iter()
+                .filter_map(|record| record.time)
+                .sum::<Duration>();
+            writeln!(
+                out,
+                "| {day} | {} | {} | {} |",
+                "⭐".repeat(solved.len()),
+                one.map(duration).unwrap_or_default(),
+                two.map(duration).unwrap_or_default(),
+            )
+            .unwrap();
+        }
+        writeln!(out, "| **Total** | {stars} ⭐ | {} | |", duration(total)).unwrap();
+    }
+    out
+}
+
+fn duration(time: Duration) -> String {
+    format!("{:.2?}", time)
+}
+
+/// Grid answers span several lines, which would break up the table.
+pub fn one_line(answer: &str) -> String {
+    answer.lines().collect::<Vec<_>>().join(" / ")
+}
+
+fn part<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(part_number(*part))
+}
+
+fn nanos<S: Serializer>(time: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+    match time {
+        Some(time) => serializer.serialize_some(&(time.as_nanos() as u64)),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::Answer;
+
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        let run = |part, result| PartRun {
+            part,
+            result,
+            time: Duration::from_millis(2),
+            memory: None,
+        };
+        vec![
+            Record::new(
+                2022,
+                1,
+                Some(Duration::from_millis(1)),
+                run(Part::One, Ok(Answer::from(24000usize))),
+                false,
+            ),
+            Record::new(
+                2022,
+                1,
+                Some(Duration::from_millis(1)),
+                run(Part::Two, Err(Error::Other("no, \"this\"".into()))),
+                false,
+            ),
+            Record::missing_input(2023, 2, Part::One),
+        ]
+    }
+
+    #[test]
+    fn formats() {
+        let records = records();
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&records, Format::Json).unwrap()).unwrap();
+        assert_eq!(json[0]["part"], 1);
+        assert_eq!(json[0]["answer"], "24000");
+        assert_eq!(json[0]["parse_ns"], 1_000_000);
+        assert_eq!(json[1]["status"], "error");
+        assert_eq!(json[2]["solve_ns"], serde_json::Value::Null);
+
+        let csv = render(&records, Format::Csv).unwrap();
+        let mut row = r#"2022,1,2,,error,1000000,2000000,"no, ""this""""#.to_string();
+        if ALLOC_STATS {
+            // The failed part has no memory to report.
+            row += ",,,";
+        }
+        assert_eq!(csv.lines().nth(2), Some(row.as_str()));
+
+        let markdown = render(&records, Format::Markdown).unwrap();
+        assert!(markdown.contains("| 1 | ⭐ | 3.00ms |  |"));
+        assert!(markdown.contains("## 2023"));
+    }
+}
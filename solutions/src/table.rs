@@ -0,0 +1,49 @@
+use std::fmt;
+
+/// Plain text table with columns padded to their widest cell.
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<S: ToString>(header: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            header: header.into_iter().map(|cell| cell.to_string()).collect(),
+            rows: vec![],
+        }
+    }
+
+    pub fn push<S: ToString>(&mut self, row: impl IntoIterator<Item = S>) {
+        self.rows
+            .push(row.into_iter().map(|cell| cell.to_string()).collect());
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut widths: Vec<usize> = self.header.iter().map(|h| h.chars().count()).collect();
+        for row in self.rows.iter() {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let line = |f: &mut fmt::Formatter<'_>, row: &[String]| {
+            let cells: Vec<_> = row
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, &width)| format!("{cell:width$}"))
+                .collect();
+            writeln!(f, "{}", cells.join("  ").trim_end())
+        };
+
+        line(f, &self.header)?;
+        let rule: Vec<_> = widths.iter().map(|&width| "-".repeat(width)).collect();
+        line(f, &rule)?;
+        for row in self.rows.iter() {
+            line(f, row)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::PathBuf,
+    sync::mpsc::{self, Receiver, SyncSender},
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+use aoc::Frame;
+use clap::{Args, ValueEnum};
+
+/// Frames waiting to be shown before the solution has to wait.
+const BUFFER: usize = 64;
+
+#[derive(Debug, Args)]
+pub struct VisualizeArgs {
+    /// Play the frames of simulations as a terminal animation
+    #[clap(long)]
+    visualize: bool,
+    /// Frames per second of the animation
+    #[clap(long, default_value_t = 10.0, requires = "visualize")]
+    fps: f64,
+    /// Write numbered frames to this directory instead of playing them
+    #[clap(long, value_name = "DIR", conflicts_with = "visualize")]
+    frames: Option<PathBuf>,
+    /// Image format of written frames
+    #[clap(long, value_enum, default_value_t, requires = "frames")]
+    image: Image,
+    /// Pixels per cell of written frames
+    #[clap(long, default_value_t = 4, requires = "frames")]
+    scale: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum Image {
+    #[default]
+    Png,
+    Ppm,
+}
+
+/// Shows or writes frames on a thread of its own while the solution runs.
+pub struct Viewer {
+    handle: JoinHandle<Result<usize, String>>,
+}
+
+impl VisualizeArgs {
+    /// Starts a viewer if frames were asked for, with the sender to put into
+    /// the [`Context`](aoc::Context) of the run.
+    pub fn start(&self) -> Option<(SyncSender<Frame>, Viewer)> {
+        if !self.visualize && self.frames.is_none() {
+            return None;
+        }
+
+        let (sender, receiver) = mpsc::sync_channel(BUFFER);
+        let frames = self.frames.clone();
+        let (fps, image, scale) = (self.fps, self.image, self.scale.max(1));
+        let handle = std::thread::spawn(move || match frames {
+            Some(dir) => write(receiver, dir, image, scale),
+            None => play(receiver, fps),
+        });
+        Some((sender, Viewer { handle }))
+    }
+}
+
+impl Viewer {
+    /// Waits for the remaining frames once every sender is dropped, reporting
+    /// how many frames there were.
+    pub fn finish(self) -> Result<usize, String> {
+        self.handle
+            .join()
+            .unwrap_or_else(|_| Err("viewer panicked".into()))
+    }
+}
+
+fn play(receiver: Receiver<Frame>, fps: f64) -> Result<usize, String> {
+    let delay = Duration::from_secs_f64(1.0 / fps.max(0.01));
+    let mut stderr = std::io::stderr().lock();
+    let mut count = 0;
+
+    for frame in receiver {
+        let start = Instant::now();
+        writeln!(stderr, "\x1b[H\x1b[2J{}", frame).map_err(|err| err.to_string())?;
+        stderr.flush().map_err(|err| err.to_string())?;
+        count += 1;
+        std::thread::sleep(delay.saturating_sub(start.elapsed()));
+    }
+    Ok(count)
+}
+
+fn write(
+    receiver: Receiver<Frame>,
+    dir: PathBuf,
+    image: Image,
+    scale: usize,
+) -> Result<usize, String> {
+    std::fs::create_dir_all(&dir).map_err(|err| err.to_string())?;
+    let mut count = 0;
+
+    for frame in receiver {
+        let extension = match image {
+            Image::Png => "png",
+            Image::Ppm => "ppm",
+        };
+        let path = dir.join(format!("frame_{count:06}.{extension}"));
+        let (width, height, pixels) = pixels(&frame, scale);
+        let file = File::create(&path).map_err(|err| format!("{}: {}", path.display(), err))?;
+        let mut out = BufWriter::new(file);
+
+        let written = match image {
+            Image::Ppm => write!(out, "P6\n{width} {height}\n255\n")
+                .and_then(|_| out.write_all(&pixels))
+                .map_err(|err| err.to_string()),
+            Image::Png => {
+                let mut encoder = png::Encoder::new(&mut out, width as u32, height as u32);
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+                encoder
+                    .write_header()
+                    .and_then(|mut writer| writer.write_image_data(&pixels))
+                    .map_err(|err| err.to_string())
+            }
+        };
+        written.map_err(|err| format!("{}: {}", path.display(), err))?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// RGB pixels of `frame`, each cell a `scale` pixels wide square.
+fn pixels(frame: &Frame, scale: usize) -> (usize, usize, Vec<u8>) {
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    let mut pixels = vec![0; width * height * 3];
+
+    for (y, row) in frame.rows().iter().enumerate() {
+        for (x, c) in row.chars().enumerate() {
+            let color = color(c);
+            for dy in 0..scale {
+                let start = ((y * scale + dy) * width + x * scale) * 3;
+                for pixel in pixels[start..start + scale * 3].chunks_mut(3) {
+                    pixel.copy_from_slice(&color);
+                }
+            }
+        }
+    }
+    (width, height, pixels)
+}
+
+/// Dark background for empty cells, white for walls and rocks, shades of
+/// grey for digits and a color picked by the character for anything else.
+fn color(c: char) -> [u8; 3] {
+    match c {
+        ' ' | '.' => [16, 16, 24],
+        '#' | '█' => [230, 230, 230],
+        '0'..='9' => {
+            let level = 40 + (c as u8 - b'0') * 23;
+            [level, level, level]
+        }
+        _ => {
+            let hash = (c as u32).wrapping_mul(2_654_435_761);
+            [
+                96 + (hash >> 24) as u8 % 160,
+                96 + (hash >> 16) as u8 % 160,
+                96 + (hash >> 8) as u8 % 160,
+            ]
+        }
+    }
+}
@@ -16,23 +16,19 @@ fn solve(input: &str, window_size: usize) -> usize {
         })
 }
 
-#[derive(Debug, Date)]
-#[date(year = 2021, day = 1)]
-pub struct Day01;
-
-impl Solution for Day01 {
-    fn part_one(&self, input: &str) -> AocResult {
-        Ok(Box::new(solve(input, 2)))
-    }
+#[aoc(year = 2021, day = 1, part = 1)]
+fn part_one(input: &str) -> usize {
+    solve(input, 2)
+}
 
-    fn part_two(&self, input: &str) -> AocResult {
-        Ok(Box::new(solve(input, 3)))
-    }
+#[aoc(year = 2021, day = 1, part = 2)]
+fn part_two(input: &str) -> usize {
+    solve(input, 3)
 }
 
 #[test]
 fn test() {
     let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
-    assert_solution!(Day01.part_one, input, "7");
-    assert_solution!(Day01.part_two, input, "5");
+    assert_eq!(part_one(input), 7);
+    assert_eq!(part_two(input), 5);
 }
@@ -19,7 +19,7 @@ impl Solution for Day02 {
                 _ => panic!("Unknown direction"),
             }
         }
-        Ok(Box::new(x * y))
+        Ok((x * y).into())
     }
 
     fn part_two(&self, input: &str) -> aoc::AocResult {
@@ -40,7 +40,7 @@ impl Solution for Day02 {
                 _ => panic!("Unknown direction"),
             }
         }
-        Ok(Box::new(x * y))
+        Ok((x * y).into())
     }
 }
 
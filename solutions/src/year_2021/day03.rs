@@ -39,7 +39,7 @@ impl Solution for Day03 {
 
         let mask = (1 << row_size) - 1;
         let epsilon = !gamma & mask;
-        Ok(Box::new(gamma * epsilon))
+        Ok((gamma * epsilon).into())
     }
 }
 
@@ -117,12 +117,12 @@ impl Solution for Day04 {
         for num in nums.iter() {
             for board in boards.iter_mut() {
                 if board.mark(*num) {
-                    return Ok(Box::new(board.unmarked_sum() * num));
+                    return Ok((board.unmarked_sum() * num).into());
                 }
             }
         }
 
-        Ok(Box::new(0))
+        Ok(0.into())
     }
 
     fn part_two(&self, input: &str) -> aoc::AocResult {
@@ -138,7 +138,7 @@ impl Solution for Day04 {
             }
         }
 
-        Ok(Box::new(last_winner_score))
+        Ok(last_winner_score.into())
     }
 }
 
@@ -47,11 +47,11 @@ pub struct Day06;
 
 impl Solution for Day06 {
     fn part_one(&self, input: &str) -> AocResult {
-        Ok(Box::new(solve(parse(input), 80)))
+        Ok(solve(parse(input), 80).into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
-        Ok(Box::new(solve(parse(input), 256)))
+        Ok(solve(parse(input), 256).into())
     }
 }
 
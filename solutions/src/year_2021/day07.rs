@@ -29,11 +29,11 @@ pub struct Day07;
 
 impl Solution for Day07 {
     fn part_one(&self, input: &str) -> aoc::AocResult {
-        Ok(Box::new(solve(input, identity)))
+        Ok(solve(input, identity).into())
     }
 
     fn part_two(&self, input: &str) -> aoc::AocResult {
-        Ok(Box::new(solve(input, |d| d * (d + 1) / 2)))
+        Ok(solve(input, |d| d * (d + 1) / 2).into())
     }
 }
 
@@ -91,11 +91,11 @@ pub struct Day09;
 
 impl Solution for Day09 {
     fn part_one(&self, input: &str) -> AocResult {
-        Ok(Box::new(HeightMap::from(input).risk()))
+        Ok(HeightMap::from(input).risk().into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
-        Ok(Box::new(HeightMap::from(input).basins()))
+        Ok(HeightMap::from(input).basins().into())
     }
 }
 
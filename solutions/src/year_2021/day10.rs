@@ -35,7 +35,7 @@ impl Solution for Day10 {
             let (_incomplete, error_score) = check_parens(line);
             acc + error_score
         });
-        Ok(Box::new(result))
+        Ok(result.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -54,7 +54,7 @@ impl Solution for Day10 {
 
         scores.sort();
 
-        Ok(Box::new(scores[scores.len() / 2]))
+        Ok(scores[scores.len() / 2].into())
     }
 }
 
@@ -1,26 +1,14 @@
-use std::fmt::Write;
-
 use aoc::*;
 
 struct Octopi {
     data: Vec<u8>,
 }
 
-impl std::fmt::Display for Octopi {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in self
-            .data
-            .iter()
-            .map(|b| (b + b'0') as char)
-            .collect::<Vec<char>>()
-            .chunks(10)
-        {
-            for c in row {
-                f.write_char(*c)?;
-            }
-            f.write_char('\n')?;
-        }
-        Ok(())
+impl Visualize for Octopi {
+    fn frame(&self) -> Frame {
+        Frame::from_fn(10, self.data.len() / 10, |x, y| {
+            (self.data[y * 10 + x] + b'0') as char
+        })
     }
 }
 
@@ -29,7 +17,7 @@ impl Octopi {
         let data = input
             .trim()
             .lines()
-            .flat_map(|line| line.bytes().map(|c| (c - b'0')))
+            .flat_map(|line| line.bytes().map(|c| c - b'0'))
             .collect::<Vec<u8>>();
         Self { data }
     }
@@ -90,14 +78,26 @@ pub struct Day11;
 impl Solution for Day11 {
     fn part_one(&self, input: &str) -> AocResult {
         let mut octopi = Octopi::parse(input);
-        let result = (0..100).map(|_| octopi.step()).sum::<usize>();
-        Ok(Box::new(result))
+        let result = (0..100)
+            .map(|_| {
+                let flashes = octopi.step();
+                emit_frame(&octopi);
+                flashes
+            })
+            .sum::<usize>();
+        Ok(result.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
         let mut octopi = Octopi::parse(input);
-        let result = (1..).find(|_| octopi.step() == 100).unwrap();
-        Ok(Box::new(result))
+        let result = (1..)
+            .find(|_| {
+                let flashes = octopi.step();
+                emit_frame(&octopi);
+                flashes == 100
+            })
+            .unwrap();
+        Ok(result.into())
     }
 }
 
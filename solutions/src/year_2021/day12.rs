@@ -72,12 +72,12 @@ pub struct Day12;
 impl Solution for Day12 {
     fn part_one(&self, input: &str) -> AocResult {
         let system = System::from(input);
-        Ok(Box::new(system.find_paths(false)))
+        Ok(system.find_paths(false).into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
         let system = System::from(input);
-        Ok(Box::new(system.find_paths(true)))
+        Ok(system.find_paths(true).into())
     }
 }
 
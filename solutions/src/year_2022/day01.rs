@@ -8,28 +8,32 @@ pub struct Day01;
 
 impl Solution for Day01 {
     fn part_one(&self, input: &str) -> AocResult {
-        let sums = sum_calories(input);
-        Ok(Box::new(sums.first().unwrap().to_owned()))
+        let sums = sum_calories(input)?;
+        sums.first()
+            .map(|&most| most.into())
+            .ok_or_else(|| Error::InvalidInput("no elves".into()))
     }
     fn part_two(&self, input: &str) -> AocResult {
-        let sums = sum_calories(input);
-        Ok(Box::new(sums.iter().take(3).sum::<usize>()))
+        let sums = sum_calories(input)?;
+        Ok(sums.iter().take(3).sum::<usize>().into())
     }
 }
 
-fn sum_calories(input: &str) -> Vec<usize> {
-    let lines = input.lines().collect::<Vec<&str>>();
-    let mut sums = lines
-        .split(|line| line.is_empty())
-        .map(|group| {
-            group
-                .iter()
-                .map(|line| line.parse::<usize>().unwrap())
-                .sum::<usize>()
+fn sum_calories(input: &str) -> Result<Vec<usize>, Error> {
+    let input = Input::new(input);
+    let mut sums = input
+        .blocks()
+        .map(|elf| {
+            elf.lines()
+                .map(|line| {
+                    line.parse::<usize>()
+                        .map_err(|_| Error::parse(input.as_str(), line, "calories"))
+                })
+                .sum()
         })
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<usize>, Error>>()?;
     sums.sort_by_key(|&x| Reverse(x));
-    sums
+    Ok(sums)
 }
 
 #[test]
@@ -50,4 +54,13 @@ fn test() {
 10000";
     assert_solution!(Day01.part_one, input, "24000");
     assert_solution!(Day01.part_two, input, "45000");
+    assert_eq!(
+        Day01.part_one("1000\n\n20x0"),
+        Err(Error::Parse {
+            line: 3,
+            column: 1,
+            expected: "calories".into(),
+            snippet: "20x0".into(),
+        })
+    );
 }
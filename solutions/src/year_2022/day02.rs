@@ -27,23 +27,21 @@ pub struct Day02;
 
 impl Solution for Day02 {
     fn part_one(&self, input: &str) -> AocResult {
-        Ok(Box::new(
-            input.chars().collect::<Vec<_>>()[..]
-                .chunks(4)
-                .map(|x| (x[0] as i32 & 3, x[2] as i32 & 3))
-                .map(|(a, b)| b + 1 + (b - a + 2).modulo(3) * 3)
-                .sum::<i32>(),
-        ))
+        Ok(input.chars().collect::<Vec<_>>()[..]
+            .chunks(4)
+            .map(|x| (x[0] as i32 & 3, x[2] as i32 & 3))
+            .map(|(a, b)| b + 1 + (b - a + 2).modulo(3) * 3)
+            .sum::<i32>()
+            .into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
-        Ok(Box::new(
-            input.chars().collect::<Vec<_>>()[..]
-                .chunks(4)
-                .map(|x| (x[0] as i32 & 3, x[2] as i32 & 3))
-                .map(|(a, b)| 1 + 3 * b + (a + b - 2).modulo(3))
-                .sum::<i32>(),
-        ))
+        Ok(input.chars().collect::<Vec<_>>()[..]
+            .chunks(4)
+            .map(|x| (x[0] as i32 & 3, x[2] as i32 & 3))
+            .map(|(a, b)| 1 + 3 * b + (a + b - 2).modulo(3))
+            .sum::<i32>()
+            .into())
     }
 }
 
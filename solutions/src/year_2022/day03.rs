@@ -25,7 +25,7 @@ impl Solution for Day03 {
             hr.extend(r);
             sum += hl.intersection(&hr).sum::<u64>();
         }
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -44,7 +44,7 @@ impl Solution for Day03 {
                 .filter(|k| other_sets.iter().all(|set| set.contains(*k)))
                 .sum::<u64>();
         }
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 }
 
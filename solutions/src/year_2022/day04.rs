@@ -15,14 +15,14 @@ impl Solution for Day04 {
         let count = iterator(input, terminated(pair, multispace1))
             .filter(|[a, b, c, d]| (a >= c && b <= d) || (c >= a && d <= b))
             .count();
-        Ok(Box::new(count))
+        Ok(count.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
         let count = iterator(input, terminated(pair, multispace1))
             .filter(|[a, b, c, d]| a <= d && b >= c)
             .count();
-        Ok(Box::new(count))
+        Ok(count.into())
     }
 }
 
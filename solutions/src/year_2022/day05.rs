@@ -39,7 +39,7 @@ impl Solution for Day05 {
         }
 
         let result: String = stacks.iter().filter_map(|x| x.last()).collect();
-        Ok(Box::new(result))
+        Ok(result.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -55,7 +55,7 @@ impl Solution for Day05 {
         }
 
         let result: String = stacks.iter().filter_map(|x| x.last()).collect();
-        Ok(Box::new(result))
+        Ok(result.into())
     }
 }
 
@@ -9,13 +9,13 @@ impl Solution for Day06 {
     fn part_one(&self, input: &str) -> AocResult {
         let chars = input.chars().collect::<Vec<_>>();
         let message_start = solve(&chars, 4);
-        Ok(Box::new(message_start))
+        Ok(message_start.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
         let chars = input.chars().collect::<Vec<_>>();
         let message_start = solve(&chars, 14);
-        Ok(Box::new(message_start))
+        Ok(message_start.into())
     }
 }
 
@@ -27,10 +27,3 @@ fn solve(chars: &[char], window_size: usize) -> usize {
     }
     0
 }
-
-#[test]
-fn test() {
-    let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
-    assert_solution!(Day06.part_one, input, "7");
-    assert_solution!(Day06.part_two, input, "19");
-}
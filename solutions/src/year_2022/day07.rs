@@ -6,26 +6,40 @@ use aoc::*;
 #[date(year = 2022, day = 7)]
 pub struct Day07;
 
-impl Solution for Day07 {
-    fn part_one(&self, input: &str) -> AocResult {
-        let sizes = dir_sizes(input);
+#[derive(Debug, FromParams)]
+pub struct Disk {
+    #[param(default = "70_000_000")]
+    total_space: usize,
+    #[param(default = "30_000_000")]
+    required_space: usize,
+}
+
+impl ParsedSolution for Day07 {
+    type Input<'a> = HashMap<Vec<&'a str>, usize>;
+    type Params = Disk;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        Ok(dir_sizes(input))
+    }
 
-        let sum: usize = sizes.into_values().filter(|size| *size < 10_0000).sum();
+    fn part_one(&self, sizes: &Self::Input<'_>, _: &Disk) -> AocResult {
+        let sum: usize = sizes.values().filter(|&&size| size < 10_0000).sum();
 
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 
-    fn part_two(&self, input: &str) -> AocResult {
-        let sizes = dir_sizes(input);
-        let free = 70_000_000 - sizes.get(&vec!["/"]).unwrap_or(&0);
+    fn part_two(&self, sizes: &Self::Input<'_>, disk: &Disk) -> AocResult {
+        let used = *sizes.get(&vec!["/"]).unwrap_or(&0);
+        let free = disk.total_space.saturating_sub(used);
 
-        let smallest_dir_to_delete = dir_sizes(input)
-            .into_values()
-            .filter(|size| free + size >= 30_000_000)
+        let smallest_dir_to_delete = sizes
+            .values()
+            .copied()
+            .filter(|size| free + size >= disk.required_space)
             .min()
             .unwrap_or_default();
 
-        Ok(Box::new(smallest_dir_to_delete))
+        Ok(smallest_dir_to_delete.into())
     }
 }
 
@@ -19,29 +19,15 @@ fn check_visibility(x: usize, y: usize, forest: &Array2<u32>, visibility: &mut A
     }
 }
 
-fn parse_forest(input: &str) -> Result<Array2<u32>, ndarray::ShapeError> {
-    let mut h = 0;
-    let data: Vec<u32> = input
-        .chars()
-        .filter_map(|c| match c {
-            '0'..='9' => Some(c as u32 - 48),
-            _ => {
-                h += 1;
-                None
-            }
-        })
-        .collect();
-
-    let w = data.len() / h;
-
-    let shape = (w, h);
-
-    Array2::from_shape_vec(shape, data)
+fn parse_forest(input: &str) -> Result<Array2<u32>, Error> {
+    let rows = Input::new(input).grid_with(|c| c.to_digit(10))?;
+    let shape = (rows.len(), rows.first().map_or(0, Vec::len));
+    Array2::from_shape_vec(shape, rows.concat()).map_err(|err| Error::Other(err.to_string()))
 }
 
 impl Solution for Day08 {
     fn part_one(&self, input: &str) -> AocResult {
-        let forest = parse_forest(input).unwrap();
+        let forest = parse_forest(input)?;
         let shape = forest.shape();
         let h = shape[0];
         let w = shape[1];
@@ -53,7 +39,7 @@ impl Solution for Day08 {
             }
         }
 
-        Ok(Box::new(visibility.sum()))
+        Ok(visibility.sum().into())
     }
 }
 
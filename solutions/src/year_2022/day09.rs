@@ -46,10 +46,10 @@ fn solve(input: &str, size: usize) -> usize {
 
 impl Solution for Day09 {
     fn part_one(&self, input: &str) -> AocResult {
-        Ok(Box::new(solve(input, 2)))
+        Ok(solve(input, 2).into())
     }
     fn part_two(&self, input: &str) -> AocResult {
-        Ok(Box::new(solve(input, 10)))
+        Ok(solve(input, 10).into())
     }
 }
 
@@ -8,7 +8,7 @@ use nom::{
         self,
         complete::{multispace0, multispace1},
     },
-    combinator::{eof, map, value},
+    combinator::{cut, eof, map, value},
     multi::separated_list1,
     sequence::{delimited, preceded, terminated},
     IResult,
@@ -32,7 +32,7 @@ struct Test {
 impl Test {
     #[inline(always)]
     fn eval(&self, old: u64) -> usize {
-        match old % self.divisor == 0 {
+        match old.is_multiple_of(self.divisor) {
             true => self.if_true,
             false => self.if_false,
         }
@@ -63,20 +63,29 @@ pub struct Day11;
 
 impl Solution for Day11 {
     fn part_one(&self, input: &str) -> AocResult {
-        let monkey_business = solve(input, 20, true);
+        let monkey_business = solve(input, 20, true)?;
 
-        Ok(Box::new(monkey_business))
+        Ok(monkey_business.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
-        let monkey_business = solve(input, 10_000, false);
+        let monkey_business = solve(input, 10_000, false)?;
 
-        Ok(Box::new(monkey_business))
+        Ok(monkey_business.into())
     }
 }
 
-fn solve(input: &str, rounds: usize, relief: bool) -> u64 {
-    let (_rest, mut monkeys) = parse_monkeys(input).unwrap();
+fn solve(input: &str, rounds: usize, relief: bool) -> Result<u64, Error> {
+    let mut monkeys = parse_monkeys(input).locate(input)?;
+    if let Some(target) = monkeys
+        .iter()
+        .flat_map(|m| [m.test.if_true, m.test.if_false])
+        .find(|&target| target >= monkeys.len())
+    {
+        return Err(Error::InvalidInput(format!(
+            "monkey {target} doesn't exist"
+        )));
+    }
 
     let modulo: u64 = monkeys.iter().map(|m| m.test.divisor).product();
 
@@ -105,12 +114,12 @@ fn solve(input: &str, rounds: usize, relief: bool) -> u64 {
 
     let mut inspections: Vec<u64> = monkeys.iter().map(|m| m.inspections).collect();
     inspections.sort_by_key(|&x| Reverse(x));
-    inspections.iter().take(2).product()
+    Ok(inspections.iter().take(2).product())
 }
 
 pub fn parse_monkeys(i: &str) -> IResult<&str, Vec<Monkey>> {
     terminated(
-        separated_list1(tag("\n\n"), parse_monkey),
+        separated_list1(tag("\n\n"), cut(parse_monkey)),
         preceded(multispace0, eof),
     )(i)
 }
@@ -70,7 +70,7 @@ impl Solution for Day12 {
 
         heightmap
             .shortest_path(&heightmap.start, &heightmap.end)
-            .map(|path| Box::new(path.1) as Box<dyn std::fmt::Display>)
+            .map(|path| path.1.into())
             .ok_or_else(|| Error::Other("No path found".into()))
     }
 
@@ -88,7 +88,7 @@ impl Solution for Day12 {
                     .map(|path| path.1)
             })
             .min()
-            .map(|length| Box::new(length) as Box<dyn std::fmt::Display>)
+            .map(Answer::from)
             .ok_or_else(|| Error::Other("No path found".into()))
     }
 }
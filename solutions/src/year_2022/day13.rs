@@ -2,8 +2,12 @@ use std::cmp::Ordering;
 
 use aoc::*;
 use nom::{
-    branch::alt, character::complete::char, combinator::map, multi::separated_list0,
-    sequence::delimited, IResult,
+    branch::alt,
+    character::complete::char,
+    combinator::{all_consuming, map},
+    multi::separated_list0,
+    sequence::delimited,
+    IResult,
 };
 
 #[derive(Debug, Date)]
@@ -12,7 +16,7 @@ pub struct Day13;
 
 impl Solution for Day13 {
     fn part_one(&self, input: &str) -> AocResult {
-        let packets = parse_packets(input).collect::<Vec<_>>();
+        let packets = parse_packets(input)?;
 
         let in_right_order: usize = packets
             .chunks_exact(2)
@@ -21,7 +25,7 @@ impl Solution for Day13 {
             .map(|(i, _)| i + 1)
             .sum();
 
-        Ok(Box::new(in_right_order))
+        Ok(in_right_order.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -30,14 +34,15 @@ impl Solution for Day13 {
 
         let dividers = [a.clone(), b.clone()];
 
-        let mut packets = parse_packets(input).chain(dividers).collect::<Vec<_>>();
+        let mut packets = parse_packets(input)?;
+        packets.extend(dividers);
 
         packets.sort();
 
         let pos1 = packets.iter().position(|p| p == &a).unwrap_or_default() + 1;
         let pos2 = packets.iter().position(|p| p == &b).unwrap_or_default() + 1;
 
-        Ok(Box::new(pos1 * pos2))
+        Ok((pos1 * pos2).into())
     }
 }
 
@@ -69,12 +74,12 @@ impl PartialOrd for Packet {
     }
 }
 
-fn parse_packets(input: &'_ str) -> impl Iterator<Item = Packet> + '_ {
+fn parse_packets(input: &str) -> Result<Vec<Packet>, Error> {
     input
         .lines()
         .filter(|line| !line.is_empty())
-        .filter_map(|packet| parse_packet(packet).ok())
-        .map(|(_, packet)| packet)
+        .map(|line| all_consuming(parse_packet)(line).locate(input))
+        .collect()
 }
 
 fn parse_packet(input: &str) -> IResult<&str, Packet> {
@@ -2,14 +2,7 @@ use std::collections::BTreeSet;
 
 use aoc::*;
 use itertools::Itertools;
-use nom::{
-    bytes::complete::tag,
-    character::complete::newline,
-    combinator::eof,
-    multi::separated_list1,
-    sequence::{preceded, terminated},
-    Finish, IResult,
-};
+use nom::{bytes::complete::tag, combinator::all_consuming, multi::separated_list1, IResult};
 
 #[derive(Debug, Date)]
 #[date(year = 2022, day = 14)]
@@ -17,34 +10,39 @@ pub struct Day14;
 
 impl Solution for Day14 {
     fn part_one(&self, input: &str) -> AocResult {
-        let (_, paths) = parse_paths(input).finish().unwrap();
+        let paths = parse_paths(input)?;
         let mut map = Map::from(paths);
 
         let mut steps = 0;
         while map.simulate_sand((500, 0)) {
             steps += 1;
+            emit_frame(&map);
         }
 
-        Ok(Box::new(steps))
+        Ok(steps.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
-        let (_, paths) = parse_paths(input).finish().unwrap();
+        let paths = parse_paths(input)?;
         let mut map = Map::from(paths);
         map.add_floor(-100..1000, map.max_y + 2);
 
         let mut steps = 0;
         while map.simulate_sand((500, 0)) {
             steps += 1;
+            emit_frame(&map);
         }
 
-        Ok(Box::new(steps))
+        Ok(steps.into())
     }
 }
 
 #[derive(Clone)]
 struct Map {
     data: BTreeSet<(i32, i32)>,
+    /// The paths the rocks were drawn from, to tell them from sand in frames.
+    paths: Vec<Vec<(i32, i32)>>,
+    floor: Option<i32>,
     max_y: i32,
 }
 
@@ -83,6 +81,42 @@ impl Map {
         for x in range {
             self.data.insert((x, height));
         }
+        self.floor = Some(height);
+    }
+
+    fn is_rock(&self, (x, y): (i32, i32)) -> bool {
+        self.floor == Some(y)
+            || self.paths.iter().any(|path| {
+                path.iter().tuple_windows().any(|(from, to)| {
+                    (from.0.min(to.0)..=from.0.max(to.0)).contains(&x)
+                        && (from.1.min(to.1)..=from.1.max(to.1)).contains(&y)
+                })
+            })
+    }
+}
+
+/// Rocks and sand around the paths and the sand heap, the floor is only drawn
+/// below them.
+impl Visualize for Map {
+    fn frame(&self) -> Frame {
+        let visible = || self.data.iter().filter(|&&(_, y)| y <= self.max_y + 1);
+        let (left, right) = visible()
+            .map(|&(x, _)| x)
+            .minmax()
+            .into_option()
+            .unwrap_or((500, 500));
+        let height = self.max_y + 3;
+
+        Frame::from_fn((right - left + 1) as usize, height as usize, |x, y| {
+            let pos = (left + x as i32, y as i32);
+            if !self.data.contains(&pos) {
+                '.'
+            } else if self.is_rock(pos) {
+                '#'
+            } else {
+                'o'
+            }
+        })
     }
 }
 
@@ -91,8 +125,8 @@ impl From<Vec<Vec<(i32, i32)>>> for Map {
         let mut data: BTreeSet<(i32, i32)> = BTreeSet::new();
         let mut max_y = 0;
 
-        for path in paths.into_iter() {
-            for (from, to) in path.into_iter().tuple_windows() {
+        for path in &paths {
+            for (&from, &to) in path.iter().tuple_windows() {
                 let mut from = from;
                 let dx = (to.0 - from.0).signum();
                 let dy = (to.1 - from.1).signum();
@@ -105,7 +139,12 @@ impl From<Vec<Vec<(i32, i32)>>> for Map {
                 }
             }
         }
-        Self { data, max_y }
+        Self {
+            data,
+            paths,
+            floor: None,
+            max_y,
+        }
     }
 }
 
@@ -120,8 +159,11 @@ fn parse_path(i: &str) -> IResult<&str, Vec<(i32, i32)>> {
     separated_list1(tag(" -> "), parse_point)(i)
 }
 
-fn parse_paths(i: &str) -> IResult<&str, Vec<Vec<(i32, i32)>>> {
-    terminated(separated_list1(newline, parse_path), preceded(newline, eof))(i)
+fn parse_paths(input: &str) -> Result<Vec<Vec<(i32, i32)>>, Error> {
+    input
+        .lines()
+        .map(|line| all_consuming(parse_path)(line).locate(input))
+        .collect()
 }
 
 #[test]
@@ -1,41 +1,44 @@
 use aoc::*;
-use nom::{
-    bytes::complete::tag,
-    character::complete::{multispace0, newline},
-    combinator::eof,
-    multi::separated_list1,
-    sequence::{preceded, terminated},
-    Finish, IResult,
-};
+use nom::{bytes::complete::tag, combinator::all_consuming, sequence::preceded, IResult};
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
-#[cfg(not(test))]
-const PART_1_Y: i64 = 2000000;
-#[cfg(test)]
-const PART_1_Y: i64 = 10;
-#[cfg(not(test))]
-const PART_1_RANGE: std::ops::Range<i64> = (-10000000)..10000000;
-#[cfg(test)]
-const PART_1_RANGE: std::ops::Range<i64> = (-100)..100;
-#[cfg(not(test))]
-const PART_2_MAX_HEIGHT: i64 = 4000000;
-#[cfg(test)]
-const PART_2_MAX_HEIGHT: i64 = 20;
-
 type Pos = (i64, i64);
 
 #[derive(Debug, Date)]
 #[date(year = 2022, day = 15)]
 pub struct Day15;
 
-impl Solution for Day15 {
-    fn part_one(&self, input: &str) -> AocResult {
-        let (_, report) = parse_report(input).finish().unwrap();
+#[derive(Debug, FromParams)]
+pub struct Area {
+    /// Row to count the positions without a beacon in
+    #[param(default = "2000000")]
+    row: i64,
+    #[param(default = "-10000000")]
+    min_x: i64,
+    #[param(default = "10000000")]
+    max_x: i64,
+    /// Largest x and y coordinate the distress beacon can be at
+    #[param(default = "4000000")]
+    max_coordinate: i64,
+}
+
+impl ParsedSolution for Day15 {
+    type Input<'a> = Vec<(Pos, Pos)>;
+    type Params = Area;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        parse_report(input)
+    }
 
-        let y = PART_1_Y;
-        let count = PART_1_RANGE
+    fn part_one(&self, report: &Self::Input<'_>, area: &Area) -> AocResult {
+        let context = Context::current();
+        let y = area.row;
+        let count = (area.min_x..area.max_x)
             .into_par_iter()
-            .filter(|&x| {
+            .map(|x| {
+                if x % 4096 == 0 {
+                    context.check()?;
+                }
                 let mut covered = true;
                 for &((sx, sy), (bx, by)) in report.iter() {
                     if (x, y) == (bx, by) {
@@ -47,19 +50,21 @@ impl Solution for Day15 {
                         break;
                     }
                 }
-                !covered
+                Ok::<_, Error>(usize::from(!covered))
             })
-            .count();
+            .try_reduce(|| 0, |a, b| Ok(a + b))?;
 
-        Ok(Box::new(count))
+        Ok(count.into())
     }
 
-    fn part_two(&self, input: &str) -> AocResult {
-        let (_, report) = parse_report(input).finish().unwrap();
-
-        let result = (0..=PART_2_MAX_HEIGHT).into_par_iter().find_map_any(|y| {
+    fn part_two(&self, report: &Self::Input<'_>, area: &Area) -> AocResult {
+        let context = Context::current();
+        let result = (0..=area.max_coordinate).into_par_iter().find_map_any(|y| {
+            if let Err(err) = context.check() {
+                return Some(Err(err));
+            }
             let mut x = 0;
-            while x <= PART_2_MAX_HEIGHT {
+            while x <= area.max_coordinate {
                 x += 1;
                 if report.iter().all(|&((sx, sy), (bx, by))| {
                     let beacon_distance = distance(sx, sy, bx, by);
@@ -72,14 +77,15 @@ impl Solution for Day15 {
                         false
                     }
                 }) {
-                    return Some(4000000 * x + y);
+                    return Some(Ok(4000000 * x + y));
                 }
             }
             None
         });
 
         result
-            .map(|freq| Box::new(freq) as Box<dyn std::fmt::Display>)
+            .transpose()?
+            .map(Answer::from)
             .ok_or_else(|| Error::Other("No tuning frequency found".to_string()))
     }
 }
@@ -100,11 +106,11 @@ fn parse_row(i: &str) -> IResult<&str, (Pos, Pos)> {
     Ok((i, ((x1, y1), (x2, y2))))
 }
 
-fn parse_report(i: &str) -> IResult<&str, Vec<(Pos, Pos)>> {
-    terminated(
-        separated_list1(newline, parse_row),
-        preceded(multispace0, eof),
-    )(i)
+fn parse_report(input: &str) -> Result<Vec<(Pos, Pos)>, Error> {
+    input
+        .lines()
+        .map(|line| all_consuming(parse_row)(line).locate(input))
+        .collect()
 }
 
 #[test]
@@ -124,6 +130,24 @@ Sensor at x=16, y=7: closest beacon is at x=15, y=3
 Sensor at x=14, y=3: closest beacon is at x=15, y=3
 Sensor at x=20, y=1: closest beacon is at x=15, y=3
 ";
-    assert_solution!(Day15.part_one, input, "26");
-    assert_solution!(Day15.part_two, input, "56000011");
+    assert_solution!(
+        Day15.part_one,
+        input,
+        "26",
+        row = 10,
+        min_x = -100,
+        max_x = 100
+    );
+    assert_solution!(Day15.part_two, input, "56000011", max_coordinate = 20);
+
+    let malformed = input.replace("y=7: closest", "y=7; closest");
+    assert_eq!(
+        Solution::part_one(&Day15, &malformed),
+        Err(Error::Parse {
+            line: 7,
+            column: 19,
+            expected: "tag".into(),
+            snippet: "; closest beacon is at x=2, y=10".into(),
+        })
+    );
 }
@@ -1,11 +1,10 @@
 use aoc::*;
 use hashbrown::HashMap;
-// use fnv::FnvHashMap as HashMap;
 use nom::{
     branch::alt,
     bytes::complete::{tag, take},
-    character::{self, complete::newline},
-    combinator::{iterator, map},
+    character,
+    combinator::{all_consuming, map, map_res},
     multi::separated_list1,
     sequence::preceded,
     IResult,
@@ -84,23 +83,38 @@ impl State {
     }
 }
 
-fn solve(input: &str, initial_mins_left: u8, elephants: u8) -> u64 {
+/// Parses the valves and finds the distances between the ones worth opening.
+fn network(input: &str) -> Result<State, Error> {
     let mut tunnels = HashMap::default();
     let mut flows = HashMap::default();
     let mut ids = HashMap::new();
 
-    let mut valves: Vec<_> = iterator(input, parse_line).into_iter().collect();
+    let parse = tracing::info_span!("parse").entered();
+    let mut valves = input
+        .lines()
+        .map(|line| all_consuming(parse_line)(line).locate(input))
+        .collect::<Result<Vec<_>, _>>()?;
     valves.sort_by_key(|v| v.name);
+    drop(parse);
+
+    let precompute = tracing::info_span!("precompute").entered();
 
     for (i, valve) in valves.iter_mut().enumerate() {
         let new_id = 1 << i;
         ids.insert(valve.id, new_id);
         valve.id = new_id;
     }
+    let start = valves
+        .iter()
+        .find(|valve| valve.name == "AA")
+        .map(|valve| valve.id)
+        .ok_or_else(|| Error::InvalidInput("no valve AA to start from".into()))?;
 
     for valve in valves.iter_mut() {
         for tunnel in valve.tunnels.iter_mut() {
-            *tunnel = *ids.get(tunnel).unwrap();
+            *tunnel = *ids.get(tunnel).ok_or_else(|| {
+                Error::InvalidInput(format!("valve {} has a tunnel to nowhere", valve.name))
+            })?;
         }
     }
 
@@ -111,7 +125,7 @@ fn solve(input: &str, initial_mins_left: u8, elephants: u8) -> u64 {
 
     let mut flow_valves = valves
         .into_iter()
-        .filter(|v| v.rate > 0 || v.id == 1)
+        .filter(|v| v.rate > 0 || v.id == start)
         .map(|v| v.id)
         .collect::<Vec<_>>();
 
@@ -132,18 +146,25 @@ fn solve(input: &str, initial_mins_left: u8, elephants: u8) -> u64 {
         }
     }
 
-    flow_valves.remove(0);
+    flow_valves.retain(|&valve| valve != start);
 
-    let mut cache = HashMap::default();
+    drop(precompute);
 
-    let state = State {
-        start: *ids.get(&370).unwrap(),
+    Ok(State {
+        start,
         flows,
         valves: flow_valves,
         distances,
-    };
+    })
+}
 
-    state.solve(state.start, 1, initial_mins_left, elephants, &mut cache)
+fn solve(input: &str, initial_mins_left: u8, elephants: u8) -> Result<u64, Error> {
+    let state = network(input)?;
+    let mut cache = HashMap::default();
+    let pressure = tracing::info_span!("search")
+        .in_scope(|| state.solve(state.start, state.start, initial_mins_left, elephants, &mut cache));
+    tracing::debug!(states = cache.len(), pressure, "searched");
+    Ok(pressure)
 }
 
 fn path_length(src: u64, dst: u64, tunnels: &HashMap<u64, Vec<u64>>) -> u8 {
@@ -158,27 +179,88 @@ fn path_length(src: u64, dst: u64, tunnels: &HashMap<u64, Vec<u64>>) -> u8 {
 
 impl Solution for Day16 {
     fn part_one(&self, input: &str) -> AocResult {
-        Ok(Box::new(solve(input, 30, 0)))
+        Ok(solve(input, 30, 0)?.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
-        Ok(Box::new(solve(input, 26, 1)))
+        Ok(solve(input, 26, 1)?.into())
+    }
+}
+
+/// Searches every order of opening valves once, keeping the best pressure
+/// for each set of opened valves, and pairs up disjoint sets for part two.
+#[derive(Debug, Date)]
+#[date(year = 2022, day = 16, variant = "bitset")]
+pub struct Day16Bitset;
+
+/// Best pressure released in `minutes` by set of opened valves, bit `i`
+/// standing for `state.valves[i]`.
+fn best_by_opened(state: &State, minutes: u8) -> Vec<u64> {
+    let rates: Vec<_> = state.valves.iter().map(|valve| state.flows[valve]).collect();
+    let mut best = vec![0; 1 << state.valves.len()];
+
+    let mut stack = vec![(state.start, minutes, 0usize, 0)];
+    while let Some((current, mins_left, opened, pressure)) = stack.pop() {
+        best[opened] = best[opened].max(pressure);
+        for (i, &next) in state.valves.iter().enumerate() {
+            if opened & 1 << i != 0 {
+                continue;
+            }
+            let cost = state.distances[&(current, next)] + 1;
+            if cost >= mins_left {
+                continue;
+            }
+            let mins_left = mins_left - cost;
+            let pressure = pressure + mins_left as u64 * rates[i];
+            stack.push((next, mins_left, opened | 1 << i, pressure));
+        }
     }
+    best
 }
 
-fn parse_line(i: &str) -> IResult<&str, Valve> {
-    let (i, name) = preceded(tag("Valve "), take(2usize))(i)?;
-    let id = u64::from_str_radix(name, 36).unwrap();
+impl Solution for Day16Bitset {
+    fn part_one(&self, input: &str) -> AocResult {
+        let state = network(input)?;
+        let best = tracing::info_span!("search").in_scope(|| best_by_opened(&state, 30));
+        Ok(best.into_iter().max().unwrap_or(0).into())
+    }
+
+    fn part_two(&self, input: &str) -> AocResult {
+        let state = network(input)?;
+        let mut best = tracing::info_span!("search").in_scope(|| best_by_opened(&state, 26));
+
+        // Make every entry the best of any subset, so that the elephant can
+        // take whatever is left.
+        for opened in 0..best.len() {
+            for i in 0..state.valves.len() {
+                if opened & 1 << i != 0 {
+                    best[opened] = best[opened].max(best[opened ^ 1 << i]);
+                }
+            }
+        }
+        let all = best.len() - 1;
+        let pressure = (0..best.len())
+            .map(|opened| best[opened] + best[all ^ opened])
+            .max()
+            .unwrap_or(0);
+        Ok(pressure.into())
+    }
+}
+
+fn valve_name(i: &str) -> IResult<&str, (&str, u64)> {
+    map_res(take(2usize), |name| {
+        u64::from_str_radix(name, 36).map(|id| (name, id))
+    })(i)
+}
+
+fn parse_line(i: &str) -> IResult<&str, Valve<'_>> {
+    let (i, (name, id)) = preceded(tag("Valve "), valve_name)(i)?;
     let (i, rate) = preceded(tag(" has flow rate="), character::complete::u64)(i)?;
     let (i, _) = alt((
         tag("; tunnels lead to valves "),
         tag("; tunnel leads to valve "),
     ))(i)?;
-    let (i, tunnels) = separated_list1(
-        tag(", "),
-        map(take(2usize), |id| u64::from_str_radix(id, 36).unwrap()),
-    )(i)?;
-    let (i, _) = newline(i)?;
+    let (i, tunnels) = separated_list1(tag(", "), map(valve_name, |(_, id)| id))(i)?;
     Ok((
         i,
         Valve {
@@ -189,20 +271,3 @@ fn parse_line(i: &str) -> IResult<&str, Valve> {
         },
     ))
 }
-
-#[test]
-fn test() {
-    let input = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
-Valve BB has flow rate=13; tunnels lead to valves CC, AA
-Valve CC has flow rate=2; tunnels lead to valves DD, BB
-Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
-Valve EE has flow rate=3; tunnels lead to valves FF, DD
-Valve FF has flow rate=0; tunnels lead to valves EE, GG
-Valve GG has flow rate=0; tunnels lead to valves FF, HH
-Valve HH has flow rate=22; tunnel leads to valve GG
-Valve II has flow rate=0; tunnels lead to valves AA, JJ
-Valve JJ has flow rate=21; tunnel leads to valve II
-";
-    assert_solution!(Day16.part_one, input, "1651");
-    assert_solution!(Day16.part_two, input, "1707");
-}
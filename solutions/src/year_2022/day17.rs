@@ -16,7 +16,7 @@ lazy_static! {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum Move {
+pub enum Move {
     Left = -1,
     Right = 1,
 }
@@ -74,6 +74,7 @@ impl Chamber {
             self.rocks.extend(piece.iter().map(|&p| (p.0 + x, p.1 + y)));
             rocks += 1;
             self.height = self.rocks.iter().map(|p| p.1).max().unwrap();
+            emit_frame(&self);
 
             if callback(&mut self, rocks) {
                 return self.height;
@@ -88,44 +89,86 @@ impl Chamber {
     }
 }
 
+/// Rows shown of the top of the tower.
+const VISIBLE_ROWS: i64 = 40;
+
+impl Visualize for Chamber {
+    fn frame(&self) -> Frame {
+        let top = self.height;
+        let height = (top + 1).min(VISIBLE_ROWS);
+        Frame::from_fn(9, height as usize, |x, y| {
+            let y = top - y as i64;
+            match x {
+                0 | 8 if y == 0 => '+',
+                0 | 8 => '|',
+                _ if y == 0 => '-',
+                _ if self.rocks.contains(&(x as i64 - 1, y)) => '#',
+                _ => '.',
+            }
+        })
+    }
+}
+
 #[derive(Debug, Date)]
 #[date(year = 2022, day = 17)]
 pub struct Day17;
 
-impl Solution for Day17 {
-    fn part_one(&self, input: &str) -> AocResult {
-        let moves = input.chars().map_while(|c| match c {
-            '<' => Some(Move::Left),
-            '>' => Some(Move::Right),
-            _ => None,
-        });
-
-        let chamber = Chamber::new();
-        let x = chamber.simulate(moves, |_, rocks| rocks == 2022);
+#[derive(Debug, FromParams)]
+pub struct Rocks {
+    #[param(default = "2022")]
+    part_one_rocks: usize,
+    #[param(default = "1_000_000_000_000")]
+    part_two_rocks: usize,
+}
 
-        Ok(Box::new(x))
+impl ParsedSolution for Day17 {
+    type Input<'a> = Vec<Move>;
+    type Params = Rocks;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        Ok(input
+            .chars()
+            .map_while(|c| match c {
+                '<' => Some(Move::Left),
+                '>' => Some(Move::Right),
+                _ => None,
+            })
+            .collect())
     }
 
-    fn part_two(&self, input: &str) -> AocResult {
-        let moves = input.chars().map_while(|c| match c {
-            '<' => Some(Move::Left),
-            '>' => Some(Move::Right),
-            _ => None,
+    fn part_one(&self, moves: &Self::Input<'_>, params: &Rocks) -> AocResult {
+        if params.part_one_rocks == 0 {
+            return Err(Error::InvalidInput("part_one_rocks must be positive".into()));
+        }
+        let chamber = Chamber::new();
+        let x = chamber.simulate(moves.iter().cloned(), |_, rocks| {
+            rocks == params.part_one_rocks
         });
 
+        Ok(x.into())
+    }
+
+    fn part_two(&self, moves: &Self::Input<'_>, params: &Rocks) -> AocResult {
+        let total = params.part_two_rocks;
+        if total == 0 {
+            return Err(Error::InvalidInput("part_two_rocks must be positive".into()));
+        }
         let mut cache: HashMap<(usize, usize), (usize, i64)> = HashMap::new();
 
-        let x = Chamber::new().simulate(moves, |chamber, rocks| {
-            println!("{rocks}");
+        let x = Chamber::new().simulate(moves.iter().cloned(), |chamber, rocks| {
+            tracing::trace!(rocks, height = chamber.height, "rock settled");
+            let remaining = match total.checked_sub(rocks) {
+                Some(0) | None => return true,
+                Some(remaining) => remaining,
+            };
             let state = (chamber.piece_id, chamber.mov_id);
             let x = match cache.get(&state) {
                 None => false,
                 Some(&(last_rock_count, last_height)) => {
                     let add_rocks = rocks - last_rock_count;
                     let add_height = chamber.height - last_height;
-                    if (1000000000000 - rocks) % add_rocks == 0 {
-                        chamber.height +=
-                            (1000000000000 - rocks as i64) / add_rocks as i64 * add_height;
+                    if remaining.is_multiple_of(add_rocks) {
+                        chamber.height += (remaining / add_rocks) as i64 * add_height;
                         true
                     } else {
                         false
@@ -136,7 +179,7 @@ impl Solution for Day17 {
             x
         });
 
-        Ok(Box::new(x))
+        Ok(x.into())
     }
 }
 
@@ -145,4 +188,16 @@ fn test() {
     let input = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
     assert_solution!(Day17.part_one, input, "3068");
     assert_solution!(Day17.part_two, input, "1514285714288");
+    assert_solution!(Day17.part_one, input, "17", part_one_rocks = 10);
+    assert_solution!(Day17.part_two, input, "17", part_two_rocks = 10);
+    assert_solution!(Day17.part_two, input, "3068", part_two_rocks = 2022);
+    for part in [Part::One, Part::Two] {
+        let params = Params::new()
+            .with("part_one_rocks", "0")
+            .with("part_two_rocks", "0");
+        assert!(matches!(
+            Day17.solve_with(input, part, &params),
+            Err(Error::InvalidInput(_))
+        ));
+    }
 }
@@ -3,11 +3,7 @@ use std::collections::VecDeque;
 use aoc::*;
 use glam::IVec3;
 use hashbrown::HashSet;
-use nom::{
-    character::complete::{char, multispace0},
-    combinator::iterator,
-    IResult,
-};
+use nom::{character::complete::char, combinator::all_consuming, IResult};
 
 #[inline(always)]
 fn neighbors(cube: IVec3) -> impl Iterator<Item = IVec3> {
@@ -28,10 +24,7 @@ pub struct Day18;
 
 impl Solution for Day18 {
     fn part_one(&self, input: &str) -> AocResult {
-        let mut cubes = HashSet::new();
-        for cube in iterator(input, parse_cube).into_iter() {
-            cubes.insert(cube);
-        }
+        let cubes: HashSet<_> = parse_cubes(input)?.into_iter().collect();
 
         let sum: usize = cubes
             .iter()
@@ -42,7 +35,7 @@ impl Solution for Day18 {
             })
             .sum();
 
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -51,7 +44,7 @@ impl Solution for Day18 {
         let mut max_y = 0;
         let mut max_z = 0;
 
-        for cube in iterator(input, parse_cube).into_iter() {
+        for cube in parse_cubes(input)? {
             max_x = max_x.max(cube.x);
             max_y = max_y.max(cube.y);
             max_z = max_z.max(cube.z);
@@ -86,7 +79,7 @@ impl Solution for Day18 {
             })
             .sum();
 
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 }
 
@@ -96,10 +89,16 @@ fn parse_cube(i: &str) -> IResult<&str, IVec3> {
     let (i, y) = nom::character::complete::i32(i)?;
     let (i, _) = char(',')(i)?;
     let (i, z) = nom::character::complete::i32(i)?;
-    let (i, _) = multispace0(i)?;
     Ok((i, IVec3::new(x, y, z)))
 }
 
+fn parse_cubes(input: &str) -> Result<Vec<IVec3>, Error> {
+    input
+        .lines()
+        .map(|line| all_consuming(parse_cube)(line).locate(input))
+        .collect()
+}
+
 #[test]
 fn test() {
     let input = "2,2,2
@@ -217,7 +217,7 @@ impl Solution for Day19 {
                 score * (i + 1) as u64
             })
             .sum();
-        Ok(Box::new(result))
+        Ok(result.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -232,7 +232,7 @@ impl Solution for Day19 {
                 dfs(&state, &bp, 0, &mut max, &mut HashMap::new()) as u64
             })
             .product();
-        Ok(Box::new(result))
+        Ok(result.into())
     }
 }
 
@@ -35,12 +35,12 @@ fn solve(input: &str, key: i64, rounds: usize) -> i64 {
 impl Solution for Day20 {
     fn part_one(&self, input: &str) -> AocResult {
         let result = solve(input, 1, 1);
-        Ok(Box::new(result))
+        Ok(result.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
         let result = solve(input, 811589153, 10);
-        Ok(Box::new(result))
+        Ok(result.into())
     }
 }
 
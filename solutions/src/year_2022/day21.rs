@@ -3,9 +3,9 @@ use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{alpha1, multispace0},
-    combinator::{iterator, map},
-    IResult,
+    combinator::{all_consuming, map},
     sequence::{preceded, tuple},
+    IResult,
 };
 
 use aoc::*;
@@ -16,33 +16,38 @@ pub struct Day21;
 
 impl Solution for Day21 {
     fn part_one(&self, input: &str) -> AocResult {
-        let monkeys = iterator(input, parse_row).collect::<HashMap<_, _>>();
+        let monkeys = parse_monkeys(input)?;
 
         let a = eval("root", &monkeys);
 
-        Ok(Box::new(a))
+        Ok(a.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
-        let mut monkeys = iterator(input, parse_row).collect::<HashMap<_, _>>();
+        let mut monkeys = parse_monkeys(input)?;
 
-        let root_job = match *monkeys.get("root").unwrap() {
-            Job::Num(_) => panic!(),
-            Job::Add(l, r) | Job::Sub(l, r) | Job::Mul(l, r) | Job::Div(l, r) => Job::Sub(r, l),
+        let root_job = match monkeys.get("root") {
+            None => return Err(Error::InvalidInput("no root monkey".into())),
+            Some(Job::Num(_)) => return Err(Error::InvalidInput("root yells a number".into())),
+            Some(&(Job::Add(l, r) | Job::Sub(l, r) | Job::Mul(l, r) | Job::Div(l, r))) => {
+                Job::Sub(r, l)
+            }
         };
         monkeys.insert("root", root_job);
 
+        let context = Context::current();
         let mut lower = 0;
         let mut upper = 0;
-        let mut human = 1;
+        let mut human: i64 = 1;
 
         loop {
+            context.check()?;
             monkeys.insert("humn", Job::Num(human));
             let root = eval("root", &monkeys);
 
-            // dbg!((upper, lower, human, root));
+            tracing::trace!(lower, upper, human, root, "bisecting");
             if root == 0 {
-                return Ok(Box::new(human - 1));
+                return Ok((human - 1).into());
             }
 
             if root < 0 {
@@ -52,7 +57,9 @@ impl Solution for Day21 {
             }
 
             if lower == 0 || upper == 0 {
-                human *= 2;
+                human = human.checked_mul(2).ok_or_else(|| {
+                    Error::InvalidInput("no number makes root's numbers equal".into())
+                })?;
             } else {
                 human = (lower + upper) / 2;
             }
@@ -126,10 +133,16 @@ fn parse_row(i: &str) -> IResult<&str, (&str, Job<'_>)> {
     let (i, name) = alpha1(i)?;
     let (i, _) = tag(":")(i)?;
     let (i, job) = parse_job(i)?;
-    let (i, _) = multispace0(i)?;
     Ok((i, (name, job)))
 }
 
+fn parse_monkeys(input: &str) -> Result<Monkeys<'_>, Error> {
+    input
+        .lines()
+        .map(|line| all_consuming(parse_row)(line).locate(input))
+        .collect()
+}
+
 #[test]
 fn test() {
     let input = "root: pppw + sjmn
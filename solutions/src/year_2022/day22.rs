@@ -1,11 +1,13 @@
 use aoc::*;
-use glam::IVec2;
+use glam::{IVec2, IVec3};
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    combinator::{map, value},
+    character::complete::multispace0,
+    combinator::{all_consuming, map, value},
     multi::many1,
-    Finish, IResult,
+    sequence::terminated,
+    IResult,
 };
 
 #[derive(Debug, Date)]
@@ -29,70 +31,154 @@ fn parse_moves(input: &str) -> IResult<&str, Vec<Move>> {
 
 impl Solution for Day22 {
     fn part_one(&self, input: &str) -> AocResult {
-        let (map, moves, _face_size) = parse_input(input);
+        let (map, moves) = parse_input(input)?;
         let password = walk(&map, &moves, wrap);
-        Ok(Box::new(password))
+        Ok(password.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
-        let (map, moves, face_size) = parse_input(input);
-        let password = walk(&map, &moves, |map, pos, dir| {
-            wrap_cube(map, pos, dir, face_size)
-        });
-        Ok(Box::new(password))
+        let (map, moves) = parse_input(input)?;
+        let cube = Cube::fold(&map)?;
+        let password = walk(&map, &moves, |_, pos, dir| cube.wrap(pos, dir));
+        Ok(password.into())
     }
 }
 
-fn wrap_cube(_map: &[Vec<char>], pos: IVec2, dir: IVec2, face_size: usize) -> (IVec2, IVec2) {
-    let face_size = face_size as i32;
-    let old_local = pos % face_size;
-    let (new_face, new_dir) = match (pos.x / face_size, pos.y / face_size, dir) {
-        (2, 1, IVec2::X) => (IVec2::new(3, 2), IVec2::Y),
-        (2, 2, IVec2::Y) => (IVec2::new(0, 1), IVec2::NEG_Y),
-        (1, 1, IVec2::NEG_Y) => (IVec2::new(2, 0), IVec2::X),
-        (1, 0, IVec2::NEG_Y) => (IVec2::new(0, 3), IVec2::X),
-        (0, 3, IVec2::NEG_X) => (IVec2::new(1, 0), IVec2::Y),
-        (2, 0, IVec2::Y) => (IVec2::new(1, 1), IVec2::NEG_X),
-        (0, 2, IVec2::NEG_Y) => (IVec2::new(1, 1), IVec2::X),
-        (1, 1, IVec2::NEG_X) => (IVec2::new(0, 2), IVec2::Y),
-        (2, 0, IVec2::X) => (IVec2::new(1, 2), IVec2::NEG_X),
-        (1, 2, IVec2::Y) => (IVec2::new(0, 3), IVec2::NEG_X),
-        (0, 3, IVec2::X) => (IVec2::new(1, 2), IVec2::NEG_Y),
-        (1, 2, IVec2::X) => (IVec2::new(2, 0), IVec2::NEG_X),
-        (0, 2, IVec2::NEG_X) => (IVec2::new(1, 0), IVec2::X),
-        (1, 0, IVec2::NEG_X) => (IVec2::new(0, 2), IVec2::X),
-        (1, 1, IVec2::X) => (IVec2::new(2, 0), IVec2::NEG_Y),
-        (2, 0, IVec2::NEG_Y) => (IVec2::new(0, 3), IVec2::NEG_Y),
-        (0, 3, IVec2::Y) => (IVec2::new(2, 0), IVec2::Y),
-        (x, y, d) => todo!("{x:?} {y:?} {d:?}"),
-    };
-    let local = match (dir, new_dir) {
-        (IVec2::NEG_Y, IVec2::X) => IVec2::new(0, old_local.x),
-        (IVec2::NEG_X, IVec2::Y) => IVec2::new(old_local.y, 0),
-        (IVec2::NEG_X, IVec2::X) => IVec2::new(0, face_size - 1 - old_local.y),
-        (IVec2::X, IVec2::NEG_Y) => IVec2::new(old_local.y, face_size - 1),
-        (IVec2::X, IVec2::Y) => IVec2::new(face_size - 1 - old_local.y, 0),
-        (IVec2::Y, IVec2::NEG_Y) => IVec2::new(face_size - 1 - old_local.x, face_size - 1),
-        (IVec2::Y, IVec2::NEG_X) => IVec2::new(face_size - 1, old_local.x),
-        (IVec2::X, IVec2::NEG_X) => IVec2::new(face_size - 1, face_size - 1 - old_local.y),
-        (IVec2::NEG_Y, IVec2::NEG_Y) => IVec2::new(old_local.x, face_size - 1),
-        (IVec2::Y, IVec2::Y) => IVec2::new(old_local.x, 0),
-        (a, b) => todo!("foo {a:?} {b:?}"),
-    };
-    (new_face * face_size + local, new_dir)
+/// Where a face of the net ends up when folded into a cube: its outward
+/// normal and the directions the map's x and y axes point to on it.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    /// Position of the face in the net, in faces.
+    origin: IVec2,
+    normal: IVec3,
+    x: IVec3,
+    y: IVec3,
 }
 
-fn parse_input(input: &str) -> (Vec<Vec<char>>, Vec<Move>, usize) {
-    let (grid, moves) = input.split_once("\n\n").unwrap();
-    let max_w = grid.lines().map(|line| line.len()).max().unwrap();
-    let max_h = grid.lines().count();
-    let face_size = max_w.min(max_h) / 3;
+impl Face {
+    /// Direction on the cube of the map direction `dir`.
+    fn along(&self, dir: IVec2) -> IVec3 {
+        self.x * dir.x + self.y * dir.y
+    }
+
+    /// The face next to this one in the map direction `dir`, folded down
+    /// over their shared edge.
+    fn fold(&self, dir: IVec2) -> Face {
+        let (x, y) = if dir.x != 0 {
+            (-self.normal * dir.x, self.y)
+        } else {
+            (self.x, -self.normal * dir.y)
+        };
+        Face {
+            origin: self.origin + dir,
+            normal: self.along(dir),
+            x,
+            y,
+        }
+    }
+}
+
+/// The map folded into a cube of `size` tiles per edge.
+#[derive(Debug)]
+struct Cube {
+    size: i32,
+    faces: Vec<Face>,
+}
+
+impl Cube {
+    fn fold(map: &[Vec<char>]) -> Result<Self, Error> {
+        let not_a_net = || Error::InvalidInput("the map doesn't fold into a cube".into());
+        let tiles = map.iter().flatten().filter(|&&c| c != ' ').count();
+        let size = (1..=tiles)
+            .find(|size| 6 * size * size >= tiles)
+            .filter(|size| 6 * size * size == tiles)
+            .ok_or_else(not_a_net)? as i32;
+
+        let is_face = |origin: IVec2| {
+            let corner = origin * size;
+            corner.cmpge(IVec2::ZERO).all()
+                && map
+                    .get(corner.y as usize)
+                    .and_then(|row| row.get(corner.x as usize))
+                    .is_some_and(|&c| c != ' ')
+        };
+        let first = (0..)
+            .map(|x| IVec2::new(x, 0))
+            .take_while(|&origin| origin.x * size < map[0].len() as i32)
+            .find(|&origin| is_face(origin))
+            .ok_or_else(not_a_net)?;
+
+        let mut faces = vec![Face {
+            origin: first,
+            normal: IVec3::NEG_Z,
+            x: IVec3::X,
+            y: IVec3::Y,
+        }];
+        let mut i = 0;
+        while let Some(&face) = faces.get(i) {
+            for dir in [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y] {
+                let next = face.fold(dir);
+                if is_face(next.origin) && faces.iter().all(|f| f.origin != next.origin) {
+                    if faces.iter().any(|f| f.normal == next.normal) {
+                        return Err(not_a_net());
+                    }
+                    faces.push(next);
+                }
+            }
+            i += 1;
+        }
+        if faces.len() != 6 {
+            return Err(not_a_net());
+        }
+        Ok(Self { size, faces })
+    }
+
+    /// Where walking off the edge of a face from `pos` in direction `dir`
+    /// leads, and the direction on the face it leads to.
+    fn wrap(&self, pos: IVec2, dir: IVec2) -> (IVec2, IVec2) {
+        let size = self.size;
+        let face = self.face(pos / size);
+        let along = face.along(dir);
+        let next = self.face_with(along);
+
+        // Tile centers on the cube, with the cube's center at the origin and
+        // two units per tile.
+        let local = pos - face.origin * size;
+        let center = face.normal * size
+            + face.x * (2 * local.x + 1 - size)
+            + face.y * (2 * local.y + 1 - size);
+        let center = center + along - face.normal;
+
+        let local = IVec2::new(
+            (center.dot(next.x) + size - 1) / 2,
+            (center.dot(next.y) + size - 1) / 2,
+        );
+        let down = -face.normal;
+        let dir = IVec2::new(down.dot(next.x), down.dot(next.y));
+        (next.origin * size + local, dir)
+    }
+
+    fn face(&self, origin: IVec2) -> &Face {
+        self.faces.iter().find(|f| f.origin == origin).unwrap()
+    }
+
+    fn face_with(&self, normal: IVec3) -> &Face {
+        self.faces.iter().find(|f| f.normal == normal).unwrap()
+    }
+}
+
+type Grid = Vec<Vec<char>>;
+
+fn parse_input(input: &str) -> Result<(Grid, Vec<Move>), Error> {
+    let (grid, moves) = input
+        .split_once("\n\n")
+        .ok_or_else(|| Error::InvalidInput("no blank line before the path".into()))?;
     let map = grid
         .lines()
         .map(|line| line.chars().collect())
         .collect::<Vec<Vec<_>>>();
-    let (_, moves) = parse_moves(moves).finish().unwrap();
-    (map, moves, face_size)
+    let moves = all_consuming(terminated(parse_moves, multispace0))(moves).locate(input)?;
+    Ok((map, moves))
 }
 
 fn walk(
@@ -179,3 +265,46 @@ fn test() {
     assert_solution!(Day22.part_one, input, "6032");
     assert_solution!(Day22.part_two, input, "5031");
 }
+
+#[test]
+fn wraps_any_net() {
+    // The net of the example, of real inputs and one three faces wide.
+    let nets = [
+        ["..#.", "###.", "..##"].as_slice(),
+        [".##", ".#.", "##.", "#.."].as_slice(),
+        ["###..", "..###"].as_slice(),
+    ];
+    for net in nets {
+        let map: Grid = net
+            .iter()
+            .flat_map(|row| {
+                let row: String = row
+                    .chars()
+                    .flat_map(|c| [if c == '#' { '.' } else { ' ' }; 3])
+                    .collect();
+                [row.clone(), row.clone(), row]
+            })
+            .map(|row| row.chars().collect())
+            .collect();
+        let cube = Cube::fold(&map).unwrap();
+
+        // Walking off any edge and straight back leads to where it started.
+        for (y, row) in map.iter().enumerate() {
+            for (x, _) in row.iter().enumerate().filter(|(_, &c)| c == '.') {
+                let pos = IVec2::new(x as i32, y as i32);
+                for dir in [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y] {
+                    let next = pos + dir;
+                    let open = map
+                        .get(next.y as usize)
+                        .and_then(|row| row.get(next.x as usize))
+                        .is_some_and(|&c| c == '.');
+                    if next.cmplt(IVec2::ZERO).any() || !open {
+                        let (wrapped, wrapped_dir) = cube.wrap(pos, dir);
+                        assert_eq!(map[wrapped.y as usize][wrapped.x as usize], '.');
+                        assert_eq!(cube.wrap(wrapped, -wrapped_dir), (pos, -dir), "{net:?}");
+                    }
+                }
+            }
+        }
+    }
+}
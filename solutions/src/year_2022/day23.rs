@@ -106,6 +106,7 @@ fn simulate(elves: &mut Elves, max_rounds: usize) -> usize {
             elves.remove(from);
             elves.insert(*to);
         }
+        emit_frame(&Grove(elves));
 
         let first_move = moves.pop_front().unwrap();
         moves.push_back(first_move);
@@ -131,33 +132,27 @@ fn simulate(elves: &mut Elves, max_rounds: usize) -> usize {
     a as usize * b as usize - elves.len()
 }
 
-#[allow(unused)]
-fn show_elves(elves: &Elves) {
-    let (left, right) = elves
-        .iter()
-        .minmax_by_key(|&&elf| elf.x)
-        .into_option()
-        .unwrap();
-
-    let (top, bottom) = elves
-        .iter()
-        .minmax_by_key(|&&elf| elf.y)
-        .into_option()
-        .unwrap();
-
-    for y in top.y..=bottom.y {
-        print!("{y}\t");
-        for x in left.x..=right.x {
-            if elves.contains(&IVec2::new(x, y)) {
-                print!("#");
+/// The elves of a grove drawn within their bounding box.
+struct Grove<'a>(&'a Elves);
+
+impl Visualize for Grove<'_> {
+    fn frame(&self) -> Frame {
+        let elves = self.0;
+        let Some((left, right)) = elves.iter().map(|elf| elf.x).minmax().into_option() else {
+            return Frame::default();
+        };
+        let (top, bottom) = elves.iter().map(|elf| elf.y).minmax().into_option().unwrap();
+
+        let width = (right - left + 1) as usize;
+        let height = (bottom - top + 1) as usize;
+        Frame::from_fn(width, height, |x, y| {
+            if elves.contains(&IVec2::new(left + x as i32, top + y as i32)) {
+                '#'
             } else {
-                print!(".");
+                '.'
             }
-        }
-        println!();
+        })
     }
-    println!();
-    println!();
 }
 
 #[derive(Debug, Date)]
@@ -167,12 +162,12 @@ pub struct Day23;
 impl Solution for Day23 {
     fn part_one(&self, input: &str) -> AocResult {
         let mut elves = parse_elves(input);
-        Ok(Box::new(simulate(&mut elves, 10)))
+        Ok(simulate(&mut elves, 10).into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
         let mut elves = parse_elves(input);
-        Ok(Box::new(simulate(&mut elves, usize::MAX)))
+        Ok(simulate(&mut elves, usize::MAX).into())
     }
 }
 
@@ -83,6 +83,22 @@ impl Valley {
     }
 }
 
+impl Visualize for Valley {
+    fn frame(&self) -> Frame {
+        Frame::from_fn(self.width, self.height, |x, y| match &self.cells[y][x] {
+            Cell::Empty => '.',
+            Cell::Wall => '#',
+            Cell::Blizzards(blizzards) => match blizzards[..] {
+                [IVec2::X] => '>',
+                [IVec2::NEG_X] => '<',
+                [IVec2::Y] => 'v',
+                [IVec2::NEG_Y] => '^',
+                _ => char::from_digit(blizzards.len().min(9) as u32, 10).unwrap(),
+            },
+        })
+    }
+}
+
 fn bfs(valley: &mut Valley, start: IVec2, goal: IVec2) -> usize {
     valley.step();
     let mut queue = VecDeque::from([start]);
@@ -107,6 +123,7 @@ fn bfs(valley: &mut Valley, start: IVec2, goal: IVec2) -> usize {
         new.clear();
         steps += 1;
         valley.step();
+        emit_frame(valley);
     }
 }
 
@@ -116,7 +133,7 @@ impl Solution for Day24 {
         let start = valley.start;
         let goal = valley.end;
         let steps = bfs(&mut valley, start, goal);
-        Ok(Box::new(steps))
+        Ok(steps.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -126,7 +143,7 @@ impl Solution for Day24 {
         let mut steps = bfs(&mut valley, start, goal);
         steps += bfs(&mut valley, goal, start) + 1;
         steps += bfs(&mut valley, start, goal) + 1;
-        Ok(Box::new(steps))
+        Ok(steps.into())
     }
 }
 
@@ -88,7 +88,7 @@ impl Solution for Day25 {
             .map(|line| line.parse::<Snafu>().unwrap())
             .sum();
 
-        Ok(Box::new(sum))
+        Ok(sum.to_string().into())
     }
 }
 
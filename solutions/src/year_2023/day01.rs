@@ -62,11 +62,11 @@ pub struct Day01;
 
 impl Solution for Day01 {
     fn part_one(&self, input: &str) -> AocResult {
-        Ok(Box::new(solve(input)))
+        Ok(solve(input).into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
-        Ok(Box::new(solve2(input)))
+        Ok(solve2(input).into())
     }
 }
 
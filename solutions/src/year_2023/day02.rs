@@ -53,7 +53,7 @@ impl Solution for Day02 {
             }
         }
 
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -63,7 +63,7 @@ impl Solution for Day02 {
             sum += Game::parse(line).power();
         }
 
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 }
 
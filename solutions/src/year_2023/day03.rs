@@ -65,7 +65,7 @@ pub struct Day03;
 
 impl Solution for Day03 {
     fn part_one(&self, input: &str) -> AocResult {
-        Ok(Box::new(solve(input)))
+        Ok(solve(input).into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -129,7 +129,7 @@ impl Solution for Day03 {
             })
             .sum();
 
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 }
 
@@ -39,7 +39,7 @@ impl Solution for Day01 {
             .filter(|&n| n > 0)
             .map(|n| 2u32.pow(n as u32 - 1) as usize)
             .sum();
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -59,12 +59,12 @@ impl Solution for Day01 {
                 *c += count;
             }
 
-            dbg!(cards.iter().map(|(_, c)| c).collect::<Vec<_>>());
+            tracing::trace!(card = i + 1, wins, count, "won copies");
         }
 
         let sum: usize = cards.into_iter().map(|(_card, count)| count).sum();
 
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 }
 
@@ -1,10 +1,12 @@
 use std::ops::Range;
 
-use itertools::Itertools;
 use rayon::prelude::*;
 
 use aoc::*;
 
+/// Seeds mapped in part two between two checks of the deadline.
+const BLOCK: isize = 1 << 16;
+
 struct Conversion {
     source: Range<isize>,
     offset: isize,
@@ -15,24 +17,21 @@ struct Map {
 }
 
 impl Map {
-    fn parse(input: &str) -> Self {
-        let conversions = input
+    fn parse(input: &str, body: &str) -> Result<Self, Error> {
+        let conversions = body
             .lines()
-            .skip(1)
             .map(|line| {
-                let (a, b, c) = line
-                    .split_ascii_whitespace()
-                    .map(|n| n.parse::<isize>().unwrap())
-                    .collect_tuple()
-                    .unwrap();
+                let [a, b, c] = ints_in::<isize>(input, line)?[..] else {
+                    return Err(Error::parse(input, line, "three numbers"));
+                };
 
-                Conversion {
+                Ok(Conversion {
                     source: b..b + c,
                     offset: b - a,
-                }
+                })
             })
-            .collect();
-        Self { conversions }
+            .collect::<Result<_, Error>>()?;
+        Ok(Self { conversions })
     }
 
     fn convert(&self, seed: isize) -> isize {
@@ -45,44 +44,72 @@ impl Map {
     }
 }
 
+pub struct Almanac {
+    seeds: Vec<isize>,
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    fn location(&self, seed: isize) -> isize {
+        self.maps.iter().fold(seed, |acc, map| map.convert(acc))
+    }
+}
+
 #[derive(Debug, Date)]
 #[date(year = 2023, day = 5)]
 pub struct Day05;
 
-impl Solution for Day05 {
-    fn part_one(&self, input: &str) -> AocResult {
-        let (seeds, _) = input.split_once('\n').unwrap();
-        let seeds = seeds
-            .split_ascii_whitespace()
-            .skip(1)
-            .map(|seed| seed.parse::<isize>().unwrap());
-        let maps = input.split("\n\n").map(Map::parse).collect::<Vec<_>>();
-        let min_location = seeds
-            .into_iter()
-            .map(|seed| maps.iter().fold(seed, |acc, map| map.convert(acc)))
+impl ParsedSolution for Day05 {
+    type Input<'a> = Almanac;
+    type Params = ();
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        let input = Input::new(input);
+        let mut sections = input.sections()?.into_iter();
+        let seeds = sections
+            .next()
+            .filter(|section| section.name == "seeds")
+            .ok_or_else(|| Error::parse(input.as_str(), input.as_str(), "seeds:"))?;
+        let seeds = ints_in(input.as_str(), seeds.body)?;
+        let maps = sections
+            .map(|section| Map::parse(input.as_str(), section.body))
+            .collect::<Result<_, _>>()?;
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part_one(&self, almanac: &Almanac, _: &()) -> AocResult {
+        let min_location = almanac
+            .seeds
+            .iter()
+            .map(|&seed| almanac.location(seed))
             .min()
-            .unwrap();
-        Ok(Box::new(min_location))
+            .ok_or_else(|| Error::InvalidInput("no seeds".into()))?;
+        Ok(min_location.into())
     }
 
-    fn part_two(&self, input: &str) -> AocResult {
-        let (seeds, _) = input.split_once('\n').unwrap();
-        let seeds = seeds
-            .split_ascii_whitespace()
-            .skip(1)
-            .map(|seed| seed.parse::<isize>().unwrap())
-            .collect::<Vec<_>>();
-        let seeds = seeds
+    fn part_two(&self, almanac: &Almanac, _: &()) -> AocResult {
+        let context = Context::current();
+        let blocks = almanac
+            .seeds
             .chunks(2)
-            .flat_map(|arr| arr[0]..arr[0] + arr[1])
+            .flat_map(|arr| {
+                (arr[0]..arr[0] + arr[1])
+                    .step_by(BLOCK as usize)
+                    .map(move |start| start..(start + BLOCK).min(arr[0] + arr[1]))
+            })
             .collect::<Vec<_>>();
-        let maps = input.split("\n\n").map(Map::parse).collect::<Vec<_>>();
-        let min_location = seeds
+        let min_location = blocks
             .into_par_iter()
-            .map(|seed| maps.iter().fold(seed, |acc, map| map.convert(acc)))
+            .map(|seeds| {
+                context.check()?;
+                Ok(seeds.map(|seed| almanac.location(seed)).min())
+            })
+            .collect::<Result<Vec<_>, Error>>()?
+            .into_iter()
+            .flatten()
             .min()
-            .unwrap();
-        Ok(Box::new(min_location))
+            .ok_or_else(|| Error::InvalidInput("no seeds".into()))?;
+        Ok(min_location.into())
     }
 }
 
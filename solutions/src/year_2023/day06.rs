@@ -35,7 +35,7 @@ impl Solution for Day06 {
             .reduce(|a, b| a * b)
             .unwrap();
 
-        Ok(Box::new(result))
+        Ok(result.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -61,7 +61,7 @@ impl Solution for Day06 {
             .filter(|hold| hold * (time - hold) > distance)
             .count();
 
-        Ok(Box::new(result))
+        Ok(result.into())
     }
 }
 
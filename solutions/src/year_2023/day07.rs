@@ -82,7 +82,7 @@ impl Solution for Day07 {
             .enumerate()
             .map(|(rank, hand)| hand.bid * (rank as u32 + 1))
             .sum();
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -93,7 +93,7 @@ impl Solution for Day07 {
             .enumerate()
             .map(|(rank, hand)| hand.bid * (rank as u32 + 1))
             .sum();
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 }
 
@@ -57,7 +57,7 @@ impl Solution for Day08 {
     fn part_one(&self, input: &str) -> AocResult {
         let (dirs, map) = parse(input);
         let steps = solve(hash("AAA"), &map, dirs.into_iter().cycle());
-        Ok(Box::new(steps))
+        Ok(steps.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -72,7 +72,7 @@ impl Solution for Day08 {
 
         let lcm: u64 = steps.into_iter().lcm();
 
-        Ok(Box::new(lcm))
+        Ok(lcm.into())
     }
 }
 
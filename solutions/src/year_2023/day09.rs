@@ -62,7 +62,7 @@ impl Solution for Day09 {
         let sum = histories
             .map(|history| history.extrapolate_forward())
             .sum::<i64>();
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -70,7 +70,7 @@ impl Solution for Day09 {
         let sum = histories
             .map(|history| history.extrapolate_backward())
             .sum::<i64>();
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 }
 
@@ -87,7 +87,7 @@ impl Solution for Day10 {
     fn part_one(&self, input: &str) -> AocResult {
         let (grid, start) = parse(input);
         let path = find_path(start, &grid);
-        Ok(Box::new(path.len() / 2))
+        Ok((path.len() / 2).into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -125,7 +125,7 @@ impl Solution for Day10 {
             }
         }
 
-        Ok(Box::new(count))
+        Ok(count.into())
     }
 }
 
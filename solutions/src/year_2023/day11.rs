@@ -5,6 +5,13 @@ use itertools::Itertools;
 #[date(year = 2023, day = 11)]
 pub struct Day11;
 
+#[derive(Debug, FromParams)]
+pub struct Expansion {
+    /// How many rows or columns each empty one becomes in part two
+    #[param(default = "1_000_000")]
+    expansion: i64,
+}
+
 fn parse(input: &str) -> Vec<(i64, i64)> {
     let mut galaxies = vec![];
     for (y, line) in input.lines().enumerate() {
@@ -56,7 +63,7 @@ fn expansions(universe: &[(i64, i64)]) -> (Vec<i64>, Vec<i64>) {
 }
 
 fn solve(
-    universe: Vec<(i64, i64)>,
+    universe: &[(i64, i64)],
     expanded_rows: Vec<i64>,
     expanded_columns: Vec<i64>,
     expand_to: i64,
@@ -91,38 +98,29 @@ fn solve(
     sum
 }
 
-impl Solution for Day11 {
-    fn part_one(&self, input: &str) -> AocResult {
-        let universe = parse(input);
-        let (expanded_columns, expanded_rows) = expansions(&universe);
-        let sum = solve(universe, expanded_rows, expanded_columns, 2);
-        Ok(Box::new(sum))
+impl ParsedSolution for Day11 {
+    type Input<'a> = Vec<(i64, i64)>;
+    type Params = Expansion;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        Ok(parse(input))
     }
 
-    fn part_two(&self, input: &str) -> AocResult {
-        #[cfg(test)]
-        let expand_to = 10;
-        #[cfg(not(test))]
-        let expand_to = 1_000_000;
+    fn part_one(&self, universe: &Self::Input<'_>, _: &Expansion) -> AocResult {
+        let (expanded_columns, expanded_rows) = expansions(universe);
+        let sum = solve(universe, expanded_rows, expanded_columns, 2);
+        Ok(sum.into())
+    }
 
-        let universe = parse(input);
-        let (expanded_columns, expanded_rows) = expansions(&universe);
-        let sum = solve(universe, expanded_rows, expanded_columns, expand_to);
-        Ok(Box::new(sum))
+    fn part_two(&self, universe: &Self::Input<'_>, params: &Expansion) -> AocResult {
+        let (expanded_columns, expanded_rows) = expansions(universe);
+        let sum = solve(universe, expanded_rows, expanded_columns, params.expansion);
+        Ok(sum.into())
     }
 }
+
 #[test]
 fn test() {
-    let input = "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....";
-    assert_solution!(Day11.part_one, input, "374");
-    assert_solution!(Day11.part_two, input, "1030");
+    let input = include_str!("../../examples/2023/11/1.txt");
+    assert_solution!(Day11.part_two, input, "8410", expansion = 100);
 }
@@ -64,7 +64,7 @@ impl Solution for Day12 {
             sum += solve(&mut HashMap::new(), springs.as_bytes(), None, &groups);
         }
 
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 
     fn part_two(&self, input: &str) -> AocResult {
@@ -77,7 +77,7 @@ impl Solution for Day12 {
             sum += solve(&mut HashMap::new(), springs.as_bytes(), None, &groups);
         }
 
-        Ok(Box::new(sum))
+        Ok(sum.into())
     }
 }
 #[test]